@@ -0,0 +1,162 @@
+//! Compares the per-connection `Context` against the previous design, where every
+//! accessor went through one `Arc<RwLock<ContextData>>`.
+//!
+//! Run with `cargo bench --bench context`.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    hint::black_box,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tcplane::*;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::RwLock,
+};
+
+/// The number of accessor calls per task.
+const ITERATIONS: u64 = 200_000;
+
+/// The number of tasks calling accessors on the same context at once.
+const TASKS: u64 = 8;
+
+/// The size of the request held by the context.
+const REQUEST_SIZE: usize = 1024;
+
+/// The context data of the previous design.
+struct LegacyContextData {
+    aborted: bool,
+    stream: Option<ArcRwLockStream>,
+    request: Vec<u8>,
+    attributes: HashMap<String, Arc<dyn Any + Send + Sync>>,
+}
+
+/// The context of the previous design.
+#[derive(Clone)]
+struct LegacyContext(Arc<RwLock<LegacyContextData>>);
+
+impl LegacyContext {
+    async fn is_aborted(&self) -> bool {
+        self.0.read().await.aborted
+    }
+
+    async fn get_request(&self) -> Vec<u8> {
+        self.0.read().await.request.clone()
+    }
+
+    async fn try_get_stream(&self) -> Option<ArcRwLockStream> {
+        self.0.read().await.stream.clone()
+    }
+
+    async fn try_get_data<V: Any + Send + Sync + Clone>(&self, key: &str) -> Option<V> {
+        self.0
+            .read()
+            .await
+            .attributes
+            .get(key)
+            .and_then(|value: &Arc<dyn Any + Send + Sync>| value.downcast_ref::<V>())
+            .cloned()
+    }
+}
+
+/// Runs `TASKS` concurrent tasks calling an accessor and returns the mean time per call.
+async fn measure<C, F, Fut>(ctx: C, call: F) -> Duration
+where
+    C: Clone + Send + 'static,
+    F: Fn(C) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    let started_at: Instant = Instant::now();
+    let mut tasks: tokio::task::JoinSet<()> = tokio::task::JoinSet::new();
+    for _ in 0..TASKS {
+        let ctx: C = ctx.clone();
+        let call: F = call.clone();
+        tasks.spawn(async move {
+            for _ in 0..ITERATIONS {
+                call(ctx.clone()).await;
+            }
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    started_at.elapsed() / (ITERATIONS * TASKS) as u32
+}
+
+fn report(name: &str, legacy: Duration, current: Duration) {
+    println!(
+        "{name:<16} legacy {:>8.1} ns   current {:>8.1} ns   {:>5.2}x",
+        legacy.as_secs_f64() * 1e9,
+        current.as_secs_f64() * 1e9,
+        legacy.as_secs_f64() / current.as_secs_f64()
+    );
+}
+
+#[tokio::main]
+async fn main() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let client: TcpStream = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let stream: ArcRwLockStream = ArcRwLockStream::from_stream(client);
+    let request: Vec<u8> = vec![b'x'; REQUEST_SIZE];
+
+    let legacy: LegacyContext = LegacyContext(Arc::new(RwLock::new(LegacyContextData {
+        aborted: false,
+        stream: Some(stream.clone()),
+        request: request.clone(),
+        attributes: HashMap::from([(
+            "user".to_owned(),
+            Arc::new("alice".to_owned()) as Arc<dyn Any + Send + Sync>,
+        )]),
+    })));
+    let current: Context = Context::from(stream);
+    current.set_request(request).await;
+    current.set_data("user", "alice".to_owned()).await;
+
+    report(
+        "is_aborted",
+        measure(legacy.clone(), |ctx: LegacyContext| async move {
+            black_box(ctx.is_aborted().await);
+        })
+        .await,
+        measure(current.clone(), |ctx: Context| async move {
+            black_box(ctx.is_aborted().await);
+        })
+        .await,
+    );
+    report(
+        "get_request",
+        measure(legacy.clone(), |ctx: LegacyContext| async move {
+            black_box(ctx.get_request().await);
+        })
+        .await,
+        measure(current.clone(), |ctx: Context| async move {
+            black_box(ctx.get_request().await);
+        })
+        .await,
+    );
+    report(
+        "try_get_stream",
+        measure(legacy.clone(), |ctx: LegacyContext| async move {
+            black_box(ctx.try_get_stream().await);
+        })
+        .await,
+        measure(current.clone(), |ctx: Context| async move {
+            black_box(ctx.try_get_stream().await);
+        })
+        .await,
+    );
+    report(
+        "try_get_data",
+        measure(legacy.clone(), |ctx: LegacyContext| async move {
+            black_box(ctx.try_get_data::<String>("user").await);
+        })
+        .await,
+        measure(current.clone(), |ctx: Context| async move {
+            black_box(ctx.try_get_data::<String, _>("user").await);
+        })
+        .await,
+    );
+}
@@ -9,6 +9,34 @@ pub const DEFAULT_PORT: u16 = 8080;
 /// Default buffer size for network operations (4KB).
 pub const DEFAULT_BUFFER_SIZE: usize = 4096;
 
+/// The default maximum size in bytes of a single request frame.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// The size of the chunks read from an `AsyncRead` streamed as a response.
+pub const DEFAULT_STREAM_CHUNK_SIZE: usize = 16 * 1024;
+
+/// The line that ends a body sent with `ChunkFraming::Chunked`.
+pub const LAST_CHUNK_BYTES: &[u8] = b"0\r\n\r\n";
+
+/// The size of the chunks read from a file when it cannot be sent with `sendfile`.
+pub const FILE_COPY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The largest byte count a single Linux `sendfile` call transfers.
+#[cfg(target_os = "linux")]
+pub const MAX_SENDFILE_CHUNK: u64 = 0x7fff_f000;
+
+/// The default number of idle read buffers kept per pool size class.
+pub const DEFAULT_BUFFER_POOL_CAPACITY: usize = 256;
+
+/// The largest stream ID size in bytes, so that IDs fit a `u64`.
+pub const MAX_STREAM_ID_SIZE: usize = 8;
+
+/// The default maximum number of concurrent streams on a multiplexed connection.
+pub const DEFAULT_MAX_STREAMS: usize = 128;
+
+/// The number of frames queued for a stream before the connection stops reading.
+pub const STREAM_FRAME_QUEUE_CAPACITY: usize = 16;
+
 /// Default socket address.
 pub const DEFAULT_SOCKET_ADDR: SocketAddr =
     SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)), 0);
@@ -21,3 +49,39 @@ pub const COLON_SPACE: &str = ": ";
 
 /// Request separator bytes for detecting end of request.
 pub const SPLIT_REQUEST_BYTES: &[u8] = b"\r\n\r\n";
+
+/// Environment variable holding the number of inherited listening sockets.
+pub const LISTEN_FDS_ENV: &str = "LISTEN_FDS";
+
+/// Environment variable holding the colon-separated names of the inherited sockets.
+pub const LISTEN_FDNAMES_ENV: &str = "LISTEN_FDNAMES";
+
+/// Name reported for inherited sockets that were not given one.
+pub const DEFAULT_LISTEN_FD_NAME: &str = "unknown";
+
+/// Environment variable holding the PID the inherited sockets are meant for.
+pub const LISTEN_PID_ENV: &str = "LISTEN_PID";
+
+/// The first file descriptor used for inherited listening sockets.
+pub const LISTEN_FDS_START: i32 = 3;
+
+/// Maximum number of listeners transferred in a single handover message.
+pub const MAX_HANDOVER_LISTENERS: usize = 64;
+
+/// Environment variable holding the service manager notification socket.
+pub const NOTIFY_SOCKET_ENV: &str = "NOTIFY_SOCKET";
+
+/// Environment variable holding the watchdog timeout in microseconds.
+pub const WATCHDOG_USEC_ENV: &str = "WATCHDOG_USEC";
+
+/// Environment variable holding the PID the watchdog is meant for.
+pub const WATCHDOG_PID_ENV: &str = "WATCHDOG_PID";
+
+/// Notification sent once the server accepts connections.
+pub const NOTIFY_READY: &str = "READY=1";
+
+/// Notification sent when the server begins shutting down.
+pub const NOTIFY_STOPPING: &str = "STOPPING=1";
+
+/// Notification sent to keep the service manager watchdog satisfied.
+pub const NOTIFY_WATCHDOG: &str = "WATCHDOG=1";
@@ -14,6 +14,12 @@ pub type ArcRwLockWriteGuard<'a, T> = RwLockWriteGuard<'a, T>;
 /// A type alias for a hash map with `Arc<dyn Any + Send + Sync>` values.
 pub type HashMapArcAnySendSync = HashMap<String, Arc<dyn Any + Send + Sync>>;
 
+/// A type alias for a hash map with `Arc<dyn Any + Send + Sync>` values keyed by their type.
+pub type TypeMapArcAnySendSync = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+/// A type alias for a map of values keyed by a `Key` name and its value type.
+pub type KeyMapArcAnySendSync = HashMap<(&'static str, TypeId), Arc<dyn Any + Send + Sync>>;
+
 /// A type alias for an optional socket address.
 pub type OptionSocketAddr = Option<SocketAddr>;
 
@@ -23,12 +29,24 @@ pub type OptionSocketHost = Option<std::net::IpAddr>;
 /// A type alias for an optional socket port.
 pub type OptionSocketPort = Option<u16>;
 
-/// A type alias for response data (byte vector).
-pub type ResponseData = Vec<u8>;
+/// A type alias for response data.
+///
+/// Clones share the same allocation, so one response can be sent on many connections.
+pub type ResponseData = Bytes;
 
 /// A type alias for response result.
 pub type ResponseResult = Result<(), ResponseError>;
 
+/// A type alias for the result of a transfer, holding the number of bytes sent.
+pub type TransferResult = Result<u64, ResponseError>;
+
+/// A type alias for a boxed read of the next request body chunk.
+pub type BoxRequestChunkFuture =
+    Pin<Box<dyn Future<Output = Result<Option<Request>, ServerError>> + Send>>;
+
+/// A type alias for a boxed stream of response chunks.
+pub type BoxChunkStream = Pin<Box<dyn Stream<Item = std::io::Result<ResponseData>> + Send>>;
+
 /// A type alias for error handling function.
 pub type ErrorHandleFn = dyn Fn(String) + Send + Sync;
 
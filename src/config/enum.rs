@@ -0,0 +1,31 @@
+/// The file formats a server configuration can be loaded from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigFormat {
+    /// TOML, selected by the `.toml` extension.
+    Toml,
+    /// JSON, selected by the `.json` extension.
+    Json,
+}
+
+/// How the messages of a connection are delimited.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Framing {
+    /// A message ends at the first `SPLIT_REQUEST_BYTES`, or without one at a read
+    /// shorter than the buffer size or at the end of the connection.
+    #[default]
+    Auto,
+    /// A message ends at the first `SPLIT_REQUEST_BYTES`, or without one at the end of
+    /// the connection; short reads do not end it.
+    Terminated,
+    /// A message ends with the given bytes, which are not part of it.
+    Delimiter(Vec<u8>),
+    /// A message is preceded by its length as a big-endian `u32`.
+    LengthPrefixed,
+    /// A message is whatever has been received so far.
+    Raw,
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+/// Parses a configuration value, naming the field when it is invalid.
+///
+/// # Arguments
+///
+/// - `&str` - The field name.
+/// - `&str` - The raw value.
+///
+/// # Returns
+///
+/// - `Result<T, ConfigError>` - The parsed value, or an `InvalidField` error.
+pub(crate) fn parse_config_field<T>(field: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|error: T::Err| ConfigError::InvalidField {
+            field: field.to_owned(),
+            message: format!("cannot parse {value:?}{COLON_SPACE}{error}"),
+        })
+}
+
+/// Checks whether a host can be combined with a port into a bind address.
+///
+/// Accepted hosts are IPv4 addresses, bracketed IPv6 addresses and DNS host names.
+///
+/// # Arguments
+///
+/// - `&str` - The host to check.
+///
+/// # Returns
+///
+/// - `bool` - Whether the host is usable.
+pub(crate) fn is_valid_host(host: &str) -> bool {
+    if format!("{host}{COLON}0").parse::<SocketAddr>().is_ok() {
+        return true;
+    }
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label: &str| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|byte: u8| byte.is_ascii_alphanumeric() || byte == b'-')
+        })
+}
+
+/// Splits a comma-separated configuration value into its non-empty items.
+///
+/// # Arguments
+///
+/// - `&str` - The raw value.
+///
+/// # Returns
+///
+/// - `Vec<&str>` - The trimmed items.
+pub(crate) fn split_config_list(value: &str) -> Vec<&str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item: &&str| !item.is_empty())
+        .collect()
+}
+
+/// Decodes the escape sequences of a configuration value into bytes.
+///
+/// `\r`, `\n`, `\t`, `\0`, `\\` and `\xHH` are recognized.
+///
+/// # Arguments
+///
+/// - `&str` - The escaped value.
+///
+/// # Returns
+///
+/// - `Result<Vec<u8>, String>` - The decoded bytes, or a description of the bad escape.
+pub(crate) fn unescape_config_bytes(value: &str) -> Result<Vec<u8>, String> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(value.len());
+    let mut chars: std::str::Chars<'_> = value.chars();
+    while let Some(current) = chars.next() {
+        if current != '\\' {
+            let mut encoded: [u8; 4] = [0; 4];
+            bytes.extend_from_slice(current.encode_utf8(&mut encoded).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('r') => bytes.push(b'\r'),
+            Some('n') => bytes.push(b'\n'),
+            Some('t') => bytes.push(b'\t'),
+            Some('0') => bytes.push(0),
+            Some('\\') => bytes.push(b'\\'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                let byte: u8 =
+                    u8::from_str_radix(&hex, 16).map_err(|_| format!("invalid escape \\x{hex}"))?;
+                bytes.push(byte);
+            }
+            Some(other) => return Err(format!("invalid escape \\{other}")),
+            None => return Err("dangling escape".to_owned()),
+        }
+    }
+    Ok(bytes)
+}
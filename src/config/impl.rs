@@ -9,10 +9,229 @@ impl Default for ServerConfigData {
     /// - `Self` - A new instance with default configuration.
     #[inline(always)]
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerConfigData {
+    /// Creates a new ServerConfigData instance with default values.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new instance with default configuration.
+    pub(crate) const fn new() -> Self {
         Self {
-            host: DEFAULT_HOST.to_owned(),
+            host: Cow::Borrowed(DEFAULT_HOST),
             port: DEFAULT_PORT,
             buffer_size: DEFAULT_BUFFER_SIZE,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            buffer_pool_classes: Vec::new(),
+            buffer_pool_capacity: DEFAULT_BUFFER_POOL_CAPACITY,
+            stream_request_body: false,
+            framing: Framing::Auto,
+            pipeline_depth: 0,
+            stream_id_size: 0,
+            max_streams: DEFAULT_MAX_STREAMS,
+            listen_fd_names: Vec::new(),
+            proxy_protocol: false,
+            trusted_proxies: Vec::new(),
+        }
+    }
+
+    /// Sets a field from its textual value, as found in environment variables.
+    ///
+    /// List fields take comma-separated items, and unknown fields are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `&str` - The raw value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ConfigError>` - Ok(()) when set or ignored, or an `InvalidField` error.
+    pub(crate) fn set_field(&mut self, field: &str, value: &str) -> Result<(), ConfigError> {
+        match field {
+            "host" => self.host = Cow::Owned(value.trim().to_owned()),
+            "port" => self.port = parse_config_field(field, value)?,
+            "buffer_size" => self.buffer_size = parse_config_field(field, value)?,
+            "max_frame_size" => self.max_frame_size = parse_config_field(field, value)?,
+            "buffer_pool_classes" => {
+                self.buffer_pool_classes = split_config_list(value)
+                    .into_iter()
+                    .map(|item: &str| parse_config_field(field, item))
+                    .collect::<Result<_, _>>()?;
+            }
+            "buffer_pool_capacity" => self.buffer_pool_capacity = parse_config_field(field, value)?,
+            "listen_fd_names" => {
+                self.listen_fd_names = split_config_list(value)
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect();
+            }
+            "stream_request_body" => self.stream_request_body = parse_config_field(field, value)?,
+            "framing" => self.framing = parse_config_field(field, value)?,
+            "pipeline_depth" => self.pipeline_depth = parse_config_field(field, value)?,
+            "stream_id_size" => self.stream_id_size = parse_config_field(field, value)?,
+            "max_streams" => self.max_streams = parse_config_field(field, value)?,
+            "proxy_protocol" => self.proxy_protocol = parse_config_field(field, value)?,
+            "trusted_proxies" => {
+                self.trusted_proxies = split_config_list(value)
+                    .into_iter()
+                    .map(|item: &str| parse_config_field(field, item))
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Checks that the settings can be used to run a server.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ConfigError>` - Ok(()) when valid, or the first problem found.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !is_valid_host(&self.host) {
+            return Err(ConfigError::InvalidHost(self.host.to_string()));
+        }
+        if self.buffer_size == 0 {
+            return Err(ConfigError::ZeroBufferSize);
+        }
+        if self.max_frame_size < self.buffer_size {
+            return Err(ConfigError::MaxFrameTooSmall {
+                max_frame_size: self.max_frame_size,
+                buffer_size: self.buffer_size,
+            });
+        }
+        if self.buffer_pool_classes.contains(&0) {
+            return Err(ConfigError::InvalidField {
+                field: "buffer_pool_classes".to_owned(),
+                message: "size classes must be greater than zero".to_owned(),
+            });
+        }
+        if self.framing == Framing::Delimiter(Vec::new()) {
+            return Err(ConfigError::InvalidField {
+                field: "framing".to_owned(),
+                message: "the delimiter must not be empty".to_owned(),
+            });
+        }
+        if self.pipeline_depth > 0 && self.stream_request_body {
+            return Err(ConfigError::ConflictingOptions(
+                "pipeline_depth is set together with stream_request_body".to_owned(),
+            ));
+        }
+        if self.stream_id_size > MAX_STREAM_ID_SIZE {
+            return Err(ConfigError::InvalidField {
+                field: "stream_id_size".to_owned(),
+                message: format!("stream IDs are at most {MAX_STREAM_ID_SIZE} bytes"),
+            });
+        }
+        if self.stream_id_size > 0 && self.max_streams == 0 {
+            return Err(ConfigError::InvalidField {
+                field: "max_streams".to_owned(),
+                message: "multiplexed connections need at least one stream".to_owned(),
+            });
+        }
+        if self.stream_id_size > 0 && self.framing == Framing::Raw {
+            return Err(ConfigError::ConflictingOptions(
+                "stream_id_size needs a framing that delimits frames, not raw".to_owned(),
+            ));
+        }
+        if self.stream_id_size > 0 && (self.pipeline_depth > 0 || self.stream_request_body) {
+            return Err(ConfigError::ConflictingOptions(
+                "stream_id_size is set together with pipeline_depth or stream_request_body"
+                    .to_owned(),
+            ));
+        }
+        if self.proxy_protocol && self.trusted_proxies.is_empty() {
+            return Err(ConfigError::ConflictingOptions(
+                "proxy_protocol is enabled without any trusted_proxies".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Deserializes configuration data from a parsed document.
+    ///
+    /// When the document is rejected, each top-level field is checked on its own
+    /// so the error can name the field at fault.
+    ///
+    /// # Arguments
+    ///
+    /// - `serde_json::Value` - The parsed document.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The configuration data, or the first invalid field.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_value(value: serde_json::Value) -> Result<Self, ConfigError> {
+        let error: serde_json::Error = match serde_json::from_value::<Self>(value.clone()) {
+            Ok(data) => return Ok(data),
+            Err(error) => error,
+        };
+        if let serde_json::Value::Object(fields) = value {
+            for (field, field_value) in fields {
+                let mut single: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+                single.insert(field.clone(), field_value);
+                if let Err(error) =
+                    serde_json::from_value::<Self>(serde_json::Value::Object(single))
+                {
+                    return Err(ConfigError::InvalidField {
+                        field,
+                        message: error.to_string(),
+                    });
+                }
+            }
+        }
+        Err(ConfigError::Parse(error.to_string()))
+    }
+
+    /// Creates the read buffer pool described by the settings.
+    ///
+    /// # Returns
+    ///
+    /// - `BufferPool` - The pool, with a single `buffer_size` class when no classes are set.
+    pub(crate) fn buffer_pool(&self) -> BufferPool {
+        if self.buffer_pool_classes.is_empty() {
+            BufferPool::new([self.buffer_size], self.buffer_pool_capacity)
+        } else {
+            BufferPool::new(
+                self.buffer_pool_classes.iter().copied(),
+                self.buffer_pool_capacity,
+            )
+        }
+    }
+}
+
+/// Wraps configuration data into a shareable `ServerConfig`.
+impl From<ServerConfigData> for ServerConfig {
+    #[inline(always)]
+    fn from(data: ServerConfigData) -> Self {
+        Self(Arc::new(RwLock::new(data)))
+    }
+}
+
+impl ConfigFormat {
+    /// Selects the format matching a file extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The configuration file path.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The format, or `UnsupportedFormat`.
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        match path
+            .extension()
+            .and_then(|extension: &std::ffi::OsStr| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            _ => Err(ConfigError::UnsupportedFormat(path.display().to_string())),
         }
     }
 }
@@ -41,6 +260,125 @@ impl ServerConfig {
         Self::default()
     }
 
+    /// Starts collecting settings synchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerConfigBuilder` - A builder holding the default settings.
+    #[inline(always)]
+    pub fn builder() -> ServerConfigBuilder {
+        ServerConfigBuilder::new()
+    }
+
+    /// Loads a configuration from a TOML or JSON file, chosen by its extension.
+    ///
+    /// Fields missing from the file keep their default values, and the result is
+    /// validated before it is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `P: AsRef<Path>` - The configuration file path.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The configuration, or the reason it could not be loaded.
+    #[cfg(feature = "serde")]
+    pub fn from_file<P>(path: P) -> Result<Self, ConfigError>
+    where
+        P: AsRef<Path>,
+    {
+        let path: &Path = path.as_ref();
+        let format: ConfigFormat = ConfigFormat::from_path(path)?;
+        let text: String = std::fs::read_to_string(path).map_err(|error: std::io::Error| {
+            ConfigError::Io(format!("{}{COLON_SPACE}{error}", path.display()))
+        })?;
+        Self::from_str_with_format(&text, format)
+    }
+
+    /// Loads a configuration from TOML or JSON text.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The configuration text.
+    /// - `ConfigFormat` - The format of the text.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The configuration, or the reason it could not be parsed
+    ///   or used.
+    #[cfg(feature = "serde")]
+    pub fn from_str_with_format(text: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let value: serde_json::Value = match format {
+            ConfigFormat::Toml => toml::from_str(text)
+                .map_err(|error: toml::de::Error| ConfigError::Parse(error.to_string()))?,
+            ConfigFormat::Json => serde_json::from_str(text)
+                .map_err(|error: serde_json::Error| ConfigError::Parse(error.to_string()))?,
+        };
+        let data: ServerConfigData = ServerConfigData::from_value(value)?;
+        data.validate()?;
+        Ok(Self::from(data))
+    }
+
+    /// Loads a configuration from the process environment.
+    ///
+    /// Each field is read from `<PREFIX>_<FIELD>`, such as `TCPLANE_PORT` or
+    /// `TCPLANE_BUFFER_SIZE`. List fields take comma-separated items, variables under the
+    /// prefix that name no field are ignored, and the result is validated before it is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `P: AsRef<str>` - The variable prefix, such as `TCPLANE`; it must not be empty.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The configuration, or the first invalid field.
+    pub fn from_env<P>(prefix: P) -> Result<Self, ConfigError>
+    where
+        P: AsRef<str>,
+    {
+        Self::from_vars(prefix, std::env::vars())
+    }
+
+    /// Loads a configuration from a set of key/value pairs named like environment variables.
+    ///
+    /// Keys without the prefix, and keys under it that name no known field, are ignored.
+    /// An empty prefix fails with `ConfigError::EmptyPrefix`.
+    ///
+    /// # Arguments
+    ///
+    /// - `P: AsRef<str>` - The variable prefix, such as `TCPLANE`.
+    /// - `I: IntoIterator<Item = (K, V)>` - The variables to read.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ConfigError>` - The configuration, or the first invalid field.
+    pub fn from_vars<P, I, K, V>(prefix: P, vars: I) -> Result<Self, ConfigError>
+    where
+        P: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let prefix: &str = prefix.as_ref();
+        if prefix.is_empty() {
+            return Err(ConfigError::EmptyPrefix);
+        }
+        let separator: &str = if prefix.ends_with('_') { "" } else { "_" };
+        let mut data: ServerConfigData = ServerConfigData::default();
+        for (key, value) in vars {
+            let Some(field) = key
+                .as_ref()
+                .strip_prefix(prefix)
+                .and_then(|rest: &str| rest.strip_prefix(separator))
+            else {
+                continue;
+            };
+            data.set_field(&field.to_ascii_lowercase(), value.as_ref())?;
+        }
+        data.validate()?;
+        Ok(Self::from(data))
+    }
+
     /// Acquires a read lock on the inner configuration data.
     ///
     /// # Returns
@@ -68,13 +406,24 @@ impl ServerConfig {
         self.read().await.clone()
     }
 
+    /// Checks that the settings can be used to run a server.
+    ///
+    /// `Server::run` performs this check before binding.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ConfigError>` - Ok(()) when valid, or the first problem found.
+    pub async fn validate(&self) -> Result<(), ConfigError> {
+        self.read().await.validate()
+    }
+
     /// Gets the host address.
     ///
     /// # Returns
     ///
     /// - `String` - The host address.
     pub async fn get_host(&self) -> String {
-        self.read().await.host.clone()
+        self.read().await.host.to_string()
     }
 
     /// Gets the port number.
@@ -95,6 +444,105 @@ impl ServerConfig {
         self.read().await.buffer_size
     }
 
+    /// Gets the maximum size of a single request frame.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The maximum frame size in bytes.
+    pub async fn get_max_frame_size(&self) -> usize {
+        self.read().await.max_frame_size
+    }
+
+    /// Gets the read buffer pool size classes.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<usize>` - The size classes, empty for a single class of `buffer_size`.
+    pub async fn get_buffer_pool_classes(&self) -> Vec<usize> {
+        self.read().await.buffer_pool_classes.clone()
+    }
+
+    /// Gets the maximum number of idle read buffers kept per size class.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The per-class cap.
+    pub async fn get_buffer_pool_capacity(&self) -> usize {
+        self.read().await.buffer_pool_capacity
+    }
+
+    /// Checks whether request bodies are streamed to the hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if only the request head is read before the hooks run.
+    pub async fn get_stream_request_body(&self) -> bool {
+        self.read().await.stream_request_body
+    }
+
+    /// Gets how the messages of a connection are delimited.
+    ///
+    /// # Returns
+    ///
+    /// - `Framing` - The framing.
+    pub async fn get_framing(&self) -> Framing {
+        self.read().await.framing.clone()
+    }
+
+    /// Gets the number of framed requests of one connection handled concurrently.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The pipeline depth, or zero when a connection carries a single request.
+    pub async fn get_pipeline_depth(&self) -> usize {
+        self.read().await.pipeline_depth
+    }
+
+    /// Gets the size of the stream ID starting every frame of a multiplexed connection.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The size in bytes, or zero when connections are not multiplexed.
+    pub async fn get_stream_id_size(&self) -> usize {
+        self.read().await.stream_id_size
+    }
+
+    /// Gets the maximum number of concurrent streams on a multiplexed connection.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The stream limit.
+    pub async fn get_max_streams(&self) -> usize {
+        self.read().await.max_streams
+    }
+
+    /// Gets the names of the socket-activated listeners to accept on.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The listener names, empty to accept on all of them.
+    pub async fn get_listen_fd_names(&self) -> Vec<String> {
+        self.read().await.listen_fd_names.clone()
+    }
+
+    /// Checks whether PROXY protocol decoding is enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if a PROXY protocol header is decoded before reading the request.
+    pub async fn get_proxy_protocol(&self) -> bool {
+        self.read().await.proxy_protocol
+    }
+
+    /// Gets the upstream networks allowed to send a PROXY protocol header.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<IpCidr>` - The trusted networks.
+    pub async fn get_trusted_proxies(&self) -> Vec<IpCidr> {
+        self.read().await.trusted_proxies.clone()
+    }
+
     /// Sets the host address.
     ///
     /// # Arguments
@@ -108,7 +556,7 @@ impl ServerConfig {
     where
         H: Into<String>,
     {
-        self.write().await.host = host.into();
+        self.write().await.host = Cow::Owned(host.into());
         self
     }
 
@@ -139,4 +587,466 @@ impl ServerConfig {
         self.write().await.buffer_size = buffer_size;
         self
     }
+
+    /// Sets the maximum size of a single request frame.
+    ///
+    /// Connections sending a larger request are reported to the read error handlers.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum frame size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn max_frame_size(&self, max_frame_size: usize) -> &Self {
+        self.write().await.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// Sets the read buffer pool size classes.
+    ///
+    /// Each connection borrows a buffer from the smallest class holding `buffer_size` bytes.
+    /// The pool is created when the server starts.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = usize>` - The buffer capacities to pool.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn buffer_pool_classes<I>(&self, classes: I) -> &Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.write().await.buffer_pool_classes = classes.into_iter().collect();
+        self
+    }
+
+    /// Sets the maximum number of idle read buffers kept per size class.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The per-class cap; zero disables pooling.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn buffer_pool_capacity(&self, capacity: usize) -> &Self {
+        self.write().await.buffer_pool_capacity = capacity;
+        self
+    }
+
+    /// Enables or disables streaming of request bodies.
+    ///
+    /// When enabled, the request handed to the hooks is the head up to the first
+    /// `SPLIT_REQUEST_BYTES`, and the rest is read through `Context::get_request_body`.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to stream request bodies.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn stream_request_body(&self, enabled: bool) -> &Self {
+        self.write().await.stream_request_body = enabled;
+        self
+    }
+
+    /// Sets how the messages of a connection are delimited.
+    ///
+    /// The framing applies to the request read before the hooks run and to `Context::read_frame`.
+    ///
+    /// # Arguments
+    ///
+    /// - `Framing` - The framing.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn framing(&self, framing: Framing) -> &Self {
+        self.write().await.framing = framing;
+        self
+    }
+
+    /// Sets the number of framed requests of one connection handled concurrently.
+    ///
+    /// With a non-zero depth every frame of a connection runs through the hook chain, and
+    /// responses are written back in request order whatever order the hooks finish in.
+    /// The server does all the reading, so the `Context` read helpers are unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The pipeline depth, or zero to handle a single request per connection.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn pipeline_depth(&self, pipeline_depth: usize) -> &Self {
+        self.write().await.pipeline_depth = pipeline_depth;
+        self
+    }
+
+    /// Sets the size of the big-endian stream ID starting every frame.
+    ///
+    /// With a non-zero size each stream ID gets its own context and hook chain, running
+    /// concurrently up to `max_streams`. Later frames of a running stream are read with
+    /// `Context::read_frame`, a frame holding only the ID cancels the stream and aborts its
+    /// hooks, and every send is written as one frame tagged with the ID. The framing must
+    /// delimit frames, so `Framing::Raw` is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The size in bytes, up to `MAX_STREAM_ID_SIZE`, or zero to disable multiplexing.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn stream_id_size(&self, stream_id_size: usize) -> &Self {
+        self.write().await.stream_id_size = stream_id_size;
+        self
+    }
+
+    /// Sets the maximum number of concurrent streams on a multiplexed connection.
+    ///
+    /// Once the limit is reached the connection stops reading until a stream finishes.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The stream limit.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn max_streams(&self, max_streams: usize) -> &Self {
+        self.write().await.max_streams = max_streams;
+        self
+    }
+
+    /// Sets the names of the socket-activated listeners to accept on.
+    ///
+    /// Only sockets whose `LISTEN_FDNAMES` entry matches one of the names are used.
+    ///
+    /// # Arguments
+    ///
+    /// - `IntoIterator<Item = Into<String>>` - The listener names.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn listen_fd_names<I, S>(&self, names: I) -> &Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.write().await.listen_fd_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Enables or disables PROXY protocol decoding.
+    ///
+    /// When enabled, connections from a trusted proxy must start with a v1 or v2 header,
+    /// and connections from any other peer are read as-is.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to decode PROXY protocol headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn proxy_protocol(&self, enabled: bool) -> &Self {
+        self.write().await.proxy_protocol = enabled;
+        self
+    }
+
+    /// Adds an upstream network allowed to send a PROXY protocol header.
+    ///
+    /// # Arguments
+    ///
+    /// - `IpCidr` - The trusted network.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn trusted_proxy(&self, cidr: IpCidr) -> &Self {
+        self.write().await.trusted_proxies.push(cidr);
+        self
+    }
+}
+
+/// Extracts the collected settings from a `ServerConfigBuilder`.
+impl From<ServerConfigBuilder> for ServerConfigData {
+    #[inline(always)]
+    fn from(builder: ServerConfigBuilder) -> Self {
+        builder.data
+    }
+}
+
+impl ServerConfigBuilder {
+    /// Creates a builder holding the default settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ServerConfigBuilder instance.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            data: ServerConfigData::new(),
+        }
+    }
+
+    /// Sets the host address.
+    ///
+    /// # Arguments
+    ///
+    /// - `H: Into<String>` - The host address.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn host<H>(mut self, host: H) -> Self
+    where
+        H: Into<String>,
+    {
+        self.data.host = Cow::Owned(host.into());
+        self
+    }
+
+    /// Sets the port number.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The port number.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn port(mut self, port: u16) -> Self {
+        self.data.port = port;
+        self
+    }
+
+    /// Sets the network buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The buffer size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.data.buffer_size = buffer_size;
+        self
+    }
+
+    /// Sets the maximum size of a single request frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum frame size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.data.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// Sets the read buffer pool size classes.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = usize>` - The buffer capacities to pool.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn buffer_pool_classes<I>(mut self, classes: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.data.buffer_pool_classes = classes.into_iter().collect();
+        self
+    }
+
+    /// Sets the maximum number of idle read buffers kept per size class.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The per-class cap; zero disables pooling.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn buffer_pool_capacity(mut self, capacity: usize) -> Self {
+        self.data.buffer_pool_capacity = capacity;
+        self
+    }
+
+    /// Enables or disables streaming of request bodies.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to stream request bodies.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn stream_request_body(mut self, enabled: bool) -> Self {
+        self.data.stream_request_body = enabled;
+        self
+    }
+
+    /// Sets how the messages of a connection are delimited.
+    ///
+    /// # Arguments
+    ///
+    /// - `Framing` - The framing.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn framing(mut self, framing: Framing) -> Self {
+        self.data.framing = framing;
+        self
+    }
+
+    /// Sets the number of framed requests of one connection handled concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The pipeline depth, or zero to handle a single request per connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn pipeline_depth(mut self, pipeline_depth: usize) -> Self {
+        self.data.pipeline_depth = pipeline_depth;
+        self
+    }
+
+    /// Sets the size of the big-endian stream ID starting every frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The size in bytes, up to `MAX_STREAM_ID_SIZE`, or zero to disable multiplexing.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn stream_id_size(mut self, stream_id_size: usize) -> Self {
+        self.data.stream_id_size = stream_id_size;
+        self
+    }
+
+    /// Sets the maximum number of concurrent streams on a multiplexed connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The stream limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn max_streams(mut self, max_streams: usize) -> Self {
+        self.data.max_streams = max_streams;
+        self
+    }
+
+    /// Sets the names of the socket-activated listeners to accept on.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = S>` - The `LISTEN_FDNAMES` names to accept on.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn listen_fd_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.data.listen_fd_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Enables or disables PROXY protocol decoding.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether to decode PROXY protocol headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub const fn proxy_protocol(mut self, enabled: bool) -> Self {
+        self.data.proxy_protocol = enabled;
+        self
+    }
+
+    /// Adds an upstream network allowed to send a PROXY protocol header.
+    ///
+    /// # Arguments
+    ///
+    /// - `IpCidr` - The trusted network.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn trusted_proxy(mut self, cidr: IpCidr) -> Self {
+        self.data.trusted_proxies.push(cidr);
+        self
+    }
+
+    /// Checks the collected settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ConfigError>` - Ok(()) when valid, or the first problem found.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.data.validate()
+    }
+
+    /// Produces a shareable configuration from the collected settings.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerConfig` - The configuration.
+    pub fn build(self) -> ServerConfig {
+        ServerConfig::from(self.data)
+    }
+}
+
+/// Parses a framing from its configuration text.
+impl FromStr for Framing {
+    type Err = String;
+
+    /// Parses `auto`, `terminated`, `raw`, `length_prefixed` or `delimiter:<bytes>`, where the bytes
+    /// may use the escapes accepted in configuration values, such as `delimiter:\r\n`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The framing text.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, String>` - The framing, or a description of the problem.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(Self::Auto),
+            "terminated" => Ok(Self::Terminated),
+            "raw" => Ok(Self::Raw),
+            "length_prefixed" => Ok(Self::LengthPrefixed),
+            _ => match value.strip_prefix("delimiter:") {
+                Some(delimiter) => unescape_config_bytes(delimiter).map(Self::Delimiter),
+                None => Err(format!("unknown framing {value:?}")),
+            },
+        }
+    }
 }
@@ -1,7 +1,9 @@
+mod r#enum;
+mod r#fn;
 mod r#impl;
 mod r#struct;
 mod r#type;
 
-pub use r#struct::*;
+pub use {r#enum::*, r#struct::*};
 
-use super::*;
+use {super::*, r#fn::*};
@@ -4,14 +4,43 @@ use super::*;
 ///
 /// This structure holds all the settings for the TCP server,
 /// including network parameters and buffer sizes.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct ServerConfigData {
     /// The host address the server will bind to.
-    pub(crate) host: String,
+    pub(crate) host: Cow<'static, str>,
     /// The port number the server will listen on.
     pub(crate) port: u16,
     /// The network buffer size for read operations.
     pub(crate) buffer_size: usize,
+    /// The maximum size of a single request frame.
+    pub(crate) max_frame_size: usize,
+    /// The read buffer pool size classes, or a single class of `buffer_size` when empty.
+    pub(crate) buffer_pool_classes: Vec<usize>,
+    /// The maximum number of idle read buffers kept per size class; zero disables pooling.
+    pub(crate) buffer_pool_capacity: usize,
+    /// Whether only the request head is read before the hooks run, leaving the body to `RequestBody`.
+    pub(crate) stream_request_body: bool,
+    /// How the messages of a connection are delimited.
+    pub(crate) framing: Framing,
+    /// The number of framed requests of one connection handled concurrently, or zero to
+    /// handle a single request per connection.
+    pub(crate) pipeline_depth: usize,
+    /// The size in bytes of the big-endian stream ID starting every frame, or zero when the
+    /// connection is not multiplexed.
+    pub(crate) stream_id_size: usize,
+    /// The maximum number of concurrent streams on a multiplexed connection.
+    pub(crate) max_streams: usize,
+    /// The `LISTEN_FDNAMES` names of the socket-activated listeners to accept on, or all when empty.
+    pub(crate) listen_fd_names: Vec<String>,
+    /// Whether a PROXY protocol header is decoded before reading the request.
+    pub(crate) proxy_protocol: bool,
+    /// The upstream networks allowed to send a PROXY protocol header.
+    pub(crate) trusted_proxies: Vec<IpCidr>,
 }
 
 /// Represents the thread-safe, shareable server configuration.
@@ -20,3 +49,12 @@ pub struct ServerConfigData {
 /// to allow for safe concurrent access and modification of the server settings.
 #[derive(Clone)]
 pub struct ServerConfig(pub(super) ArcRwLock<ServerConfigData>);
+
+/// Collects server settings synchronously, without taking any lock.
+///
+/// Use `build` for a shareable `ServerConfig`, or pass the builder to `ServerBuilder::config`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServerConfigBuilder {
+    /// The settings collected so far.
+    pub(crate) data: ServerConfigData,
+}
@@ -1,5 +1,8 @@
 use super::*;
 
+/// The identifier given to the next context.
+static NEXT_CONTEXT_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Provides a default implementation for ContextData.
 impl Default for ContextData {
     /// Creates a new ContextData instance with default values.
@@ -10,12 +13,22 @@ impl Default for ContextData {
     #[inline(always)]
     fn default() -> Self {
         Self {
-            aborted: false,
-            closed: false,
-            stream: None,
-            request: Request::new(),
-            response: Response::default(),
-            attributes: HashMap::new(),
+            id: NEXT_CONTEXT_ID.fetch_add(1, Ordering::Relaxed),
+            aborted: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+            stream: ContextCell::default(),
+            proxy_header: ContextCell::default(),
+            server_error: ContextCell::default(),
+            hook_name: ContextCell::default(),
+            task_panic: ContextCell::default(),
+            request: ContextCell::default(),
+            response: ContextCell::default(),
+            reader: ContextCell::default(),
+            response_slot: ContextCell::default(),
+            multiplex: ContextCell::default(),
+            connection_attributes: Attributes::default(),
+            request_attributes: Attributes::default(),
+            state: Arc::new(HashMap::new()),
         }
     }
 }
@@ -41,7 +54,7 @@ impl Default for Context {
     /// - `Self` - A new Context wrapping default ContextData.
     #[inline(always)]
     fn default() -> Self {
-        Self(Arc::new(RwLock::new(ContextData::default())))
+        Self(Arc::new(ContextData::default()))
     }
 }
 
@@ -58,7 +71,7 @@ impl From<ContextData> for Context {
     /// - `Context` - A new Context instance.
     #[inline(always)]
     fn from(data: ContextData) -> Self {
-        Self(Arc::new(RwLock::new(data)))
+        Self(Arc::new(data))
     }
 }
 
@@ -76,7 +89,7 @@ impl From<ArcRwLockStream> for Context {
     #[inline(always)]
     fn from(stream: ArcRwLockStream) -> Self {
         let data: ContextData = ContextData {
-            stream: Some(stream),
+            stream: ContextCell::new(Some(stream)),
             ..Default::default()
         };
         Self::from(data)
@@ -95,22 +108,14 @@ impl Context {
         Self::default()
     }
 
-    /// Acquires a read lock on the inner context data.
-    ///
-    /// # Returns
-    ///
-    /// - `ArcRwLockReadGuard<ContextData>` - The read guard.
-    pub(crate) async fn read(&self) -> ArcRwLockReadGuard<'_, ContextData> {
-        self.0.read().await
-    }
-
-    /// Acquires a write lock on the inner context data.
+    /// Gets the process-unique identifier of the context.
     ///
     /// # Returns
     ///
-    /// - `ArcRwLockWriteGuard<ContextData>` - The write guard.
-    pub(crate) async fn write(&self) -> ArcRwLockWriteGuard<'_, ContextData> {
-        self.0.write().await
+    /// - `u64` - The context identifier.
+    #[inline(always)]
+    pub fn get_id(&self) -> u64 {
+        self.0.id
     }
 
     /// Checks if the context has been marked as aborted.
@@ -119,7 +124,7 @@ impl Context {
     ///
     /// - `bool` - True if the context is aborted, otherwise false.
     pub async fn is_aborted(&self) -> bool {
-        self.read().await.aborted
+        self.0.aborted.load(Ordering::Acquire)
     }
 
     /// Sets the aborted flag for the context.
@@ -132,7 +137,7 @@ impl Context {
     ///
     /// - `&Self` - Reference to self for method chaining.
     pub async fn set_aborted(&self, aborted: bool) -> &Self {
-        self.write().await.aborted = aborted;
+        self.0.aborted.store(aborted, Ordering::Release);
         self
     }
 
@@ -160,7 +165,7 @@ impl Context {
     ///
     /// - `bool` - True if the connection is closed, otherwise false.
     pub async fn is_closed(&self) -> bool {
-        self.read().await.closed
+        self.0.closed.load(Ordering::Acquire)
     }
 
     /// Sets the closed flag for the connection.
@@ -173,7 +178,7 @@ impl Context {
     ///
     /// - `&Self` - Reference to self for method chaining.
     pub async fn set_closed(&self, closed: bool) -> &Self {
-        self.write().await.closed = closed;
+        self.0.closed.store(closed, Ordering::Release);
         self
     }
 
@@ -210,7 +215,7 @@ impl Context {
     ///
     /// - `Option<ArcRwLockStream>` - The stream if available.
     pub async fn try_get_stream(&self) -> Option<ArcRwLockStream> {
-        self.read().await.stream.clone()
+        self.0.stream.get()
     }
 
     /// Gets the stream from the context.
@@ -236,7 +241,7 @@ impl Context {
     ///
     /// - `&Self` - Reference to self for method chaining.
     pub async fn set_stream(&self, stream: ArcRwLockStream) -> &Self {
-        self.write().await.stream = Some(stream);
+        self.0.stream.set(Some(stream));
         self
     }
 
@@ -246,30 +251,180 @@ impl Context {
     ///
     /// - `Request` - A clone of the request.
     pub async fn get_request(&self) -> Request {
-        self.read().await.request.clone()
+        self.0.request.get()
     }
 
     /// Sets the request in the context.
     ///
     /// # Arguments
     ///
-    /// - `Request` - The request to set.
+    /// - `R: Into<Request>` - The request to set.
     ///
     /// # Returns
     ///
     /// - `&Self` - Reference to self for method chaining.
-    pub async fn set_request(&self, request: Request) -> &Self {
-        self.write().await.request = request;
+    pub async fn set_request<R>(&self, request: R) -> &Self
+    where
+        R: Into<Request>,
+    {
+        self.0.request.set(request.into());
         self
     }
 
+    /// Attempts to get the rest of the connection as a streamed body.
+    ///
+    /// With `stream_request_body` enabled this is the body following the request head.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestBody>` - The body, or `None` if the context was not created for a connection.
+    pub async fn try_get_request_body(&self) -> Option<RequestBody> {
+        self.0.reader.get().map(RequestBody::new)
+    }
+
+    /// Gets the rest of the connection as a streamed body.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBody` - The body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context was not created for a connection.
+    pub async fn get_request_body(&self) -> RequestBody {
+        self.try_get_request_body().await.unwrap()
+    }
+
+    /// Attempts to get the ID of the stream the context was created for.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u64>` - The stream ID, or `None` if the connection is not multiplexed.
+    pub async fn try_get_stream_id(&self) -> Option<u64> {
+        self.0
+            .multiplex
+            .get()
+            .map(|multiplex: MultiplexStream| multiplex.id)
+    }
+
+    /// Gets the ID of the stream the context was created for.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The stream ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the connection is not multiplexed.
+    pub async fn get_stream_id(&self) -> u64 {
+        self.try_get_stream_id().await.unwrap()
+    }
+
+    /// Gets the reader of the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Arc<Mutex<ConnectionReader>>, ServerError>` - The reader, or `ConnectionClosed`
+    ///   if the context was not created for a readable connection.
+    fn connection_reader(&self) -> Result<Arc<Mutex<ConnectionReader>>, ServerError> {
+        self.0.reader.get().ok_or(ServerError::ConnectionClosed)
+    }
+
+    /// Reads exactly `len` more bytes from the connection.
+    ///
+    /// Bytes already received past the request are used first.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes to read.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The bytes, `FrameTooLarge` above `max_frame_size`,
+    ///   or `ConnectionClosed` if the peer closes first.
+    pub async fn read_exact(&self, len: usize) -> Result<Request, ServerError> {
+        self.connection_reader()?.lock().await.read_exact(len).await
+    }
+
+    /// Reads from the connection up to the next occurrence of a delimiter.
+    ///
+    /// The delimiter is consumed but not returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The delimiter.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The bytes before the delimiter, `FrameTooLarge` above
+    ///   `max_frame_size`, or `ConnectionClosed` if the peer closes first.
+    pub async fn read_until<D>(&self, delimiter: D) -> Result<Request, ServerError>
+    where
+        D: AsRef<[u8]>,
+    {
+        self.connection_reader()?
+            .lock()
+            .await
+            .read_until(delimiter.as_ref())
+            .await
+    }
+
+    /// Reads the next message from the connection with the configured `Framing`.
+    ///
+    /// On a multiplexed connection this is the payload of the next frame of the stream.
+    /// The message starts a new request as with `next_request`: it becomes the current
+    /// request and the request attributes are cleared.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, ServerError>` - The message, `None` if the peer closed
+    ///   between messages or cancelled the stream, or an error on failure.
+    pub async fn read_frame(&self) -> Result<Option<Request>, ServerError> {
+        let frame: Option<Request> = match self.0.multiplex.get() {
+            Some(multiplex) => multiplex.next_frame().await,
+            None => self.connection_reader()?.lock().await.read_frame().await?,
+        };
+        if let Some(frame) = &frame {
+            self.next_request(frame.clone()).await;
+        }
+        Ok(frame)
+    }
+
+    /// Reads whatever arrives next on the connection, waiting at most `timeout`.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - How long to wait for data.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, ServerError>` - The bytes, `None` once the peer has closed,
+    ///   or `ReadTimeout` if nothing arrived in time.
+    pub async fn read_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Request>, ServerError> {
+        if let Some(multiplex) = self.0.multiplex.get() {
+            return tokio::time::timeout(timeout, multiplex.next_frame())
+                .await
+                .map_err(|_| ServerError::ReadTimeout(timeout));
+        }
+        let reader: Arc<Mutex<ConnectionReader>> = self.connection_reader()?;
+        tokio::time::timeout(
+            timeout,
+            async move { reader.lock().await.next_chunk().await },
+        )
+        .await
+        .map_err(|_| ServerError::ReadTimeout(timeout))?
+    }
+
     /// Gets the response from the context.
     ///
     /// # Returns
     ///
     /// - `Response` - A clone of the response.
     pub async fn get_response(&self) -> Response {
-        self.read().await.response.clone()
+        self.0.response.get()
     }
 
     /// Sets the response in the context.
@@ -282,22 +437,180 @@ impl Context {
     ///
     /// - `&Self` - Reference to self for method chaining.
     pub async fn set_response(&self, response: Response) -> &Self {
-        self.write().await.response = response;
+        self.0.response.set(response);
+        self
+    }
+
+    /// Gets the PROXY protocol header received from a trusted upstream.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ProxyHeader>` - The header if one was decoded for this connection.
+    pub async fn try_get_proxy_header(&self) -> Option<ProxyHeader> {
+        self.0.proxy_header.get()
+    }
+
+    /// Sets the PROXY protocol header for the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `ProxyHeader` - The header to set.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_proxy_header(&self, proxy_header: ProxyHeader) -> &Self {
+        self.0.proxy_header.set(Some(proxy_header));
+        self
+    }
+
+    /// Gets the error that stopped the server from reading the request.
+    ///
+    /// It is set on the context given to `read_error` handlers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ServerError>` - The read error, if any.
+    pub async fn try_get_server_error(&self) -> Option<ServerError> {
+        self.0.server_error.get()
+    }
+
+    /// Gets the error that stopped the server from reading the request.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerError` - The read error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no read error is set.
+    pub async fn get_server_error(&self) -> ServerError {
+        self.try_get_server_error().await.unwrap()
+    }
+
+    /// Sets the error that stopped the server from reading the request.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerError` - The read error.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_server_error(&self, server_error: ServerError) -> &Self {
+        self.0.server_error.set(Some(server_error));
+        self
+    }
+
+    /// Gets the description of the hook that panicked.
+    ///
+    /// It is set on the context given to `task_panic` handlers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TaskPanic>` - The panic description, if any.
+    pub async fn try_get_task_panic(&self) -> Option<TaskPanic> {
+        self.0.task_panic.get()
+    }
+
+    /// Gets the description of the hook that panicked.
+    ///
+    /// # Returns
+    ///
+    /// - `TaskPanic` - The panic description.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no hook panic is set.
+    pub async fn get_task_panic(&self) -> TaskPanic {
+        self.try_get_task_panic().await.unwrap()
+    }
+
+    /// Sets the description of the hook that panicked.
+    ///
+    /// # Arguments
+    ///
+    /// - `TaskPanic` - The panic description.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_task_panic(&self, task_panic: TaskPanic) -> &Self {
+        self.0.task_panic.set(Some(task_panic));
         self
     }
 
-    /// Attempts to get the socket address from the stream.
+    /// Records the type name of the hook about to run.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<&'static str>` - The hook type name.
+    pub(crate) async fn set_hook_name(&self, hook_name: Option<&'static str>) {
+        self.0.hook_name.set(hook_name);
+    }
+
+    /// Gets the type name of the hook that ran last.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static str>` - The hook type name.
+    pub(crate) async fn try_get_hook_name(&self) -> Option<&'static str> {
+        self.0.hook_name.get()
+    }
+
+    /// Attempts to get the socket address of the client.
+    ///
+    /// The original source from a PROXY protocol header takes precedence over the peer address.
     ///
     /// # Returns
     ///
     /// - `OptionSocketAddr` - The socket address if available.
     pub async fn try_get_socket_addr(&self) -> OptionSocketAddr {
-        if let Some(stream) = self.try_get_stream().await {
+        let (stream, proxy_header): (Option<ArcRwLockStream>, Option<ProxyHeader>) =
+            (self.0.stream.get(), self.0.proxy_header.get());
+        if let Some(source) = proxy_header.and_then(|header: ProxyHeader| header.get_source()) {
+            return Some(source);
+        }
+        if let Some(stream) = stream {
             return stream.try_get_peer_addr().await;
         }
         None
     }
 
+    /// Attempts to get the local address the client connected to.
+    ///
+    /// The original destination from a PROXY protocol header takes precedence over the local address.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionSocketAddr` - The local address if available.
+    pub async fn try_get_local_addr(&self) -> OptionSocketAddr {
+        let (stream, proxy_header): (Option<ArcRwLockStream>, Option<ProxyHeader>) =
+            (self.0.stream.get(), self.0.proxy_header.get());
+        if let Some(destination) =
+            proxy_header.and_then(|header: ProxyHeader| header.get_destination())
+        {
+            return Some(destination);
+        }
+        if let Some(stream) = stream {
+            return stream.try_get_local_addr().await;
+        }
+        None
+    }
+
+    /// Gets the local address the client connected to.
+    ///
+    /// # Returns
+    ///
+    /// - `SocketAddr` - The local address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the local address is not available.
+    pub async fn get_local_addr(&self) -> SocketAddr {
+        self.try_get_local_addr().await.unwrap()
+    }
+
     /// Gets the socket address.
     ///
     /// # Returns
@@ -379,7 +692,68 @@ impl Context {
         self.try_get_socket_port().await.unwrap()
     }
 
-    /// Sets a data value in the context's data map.
+    /// Gets the attributes that persist for the lifetime of the connection.
+    ///
+    /// Use them for state such as an authenticated user that must outlive one message.
+    ///
+    /// # Returns
+    ///
+    /// - `Attributes` - The connection scope.
+    pub async fn connection(&self) -> Attributes {
+        self.0.connection_attributes.clone()
+    }
+
+    /// Gets the attributes of the current message.
+    ///
+    /// They are cleared by `next_request` and by each message `read_frame` returns, so
+    /// every frame of the connection starts with an empty scope. The `set_data` family
+    /// of methods on `Context` reads and writes this scope.
+    ///
+    /// # Returns
+    ///
+    /// - `Attributes` - The request scope.
+    pub async fn request(&self) -> Attributes {
+        self.0.request_attributes.clone()
+    }
+
+    /// Starts processing the next message read from the connection.
+    ///
+    /// The request is replaced and the request attributes are cleared, while the
+    /// connection attributes are kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `R: Into<Request>` - The next message.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn next_request<R>(&self, request: R) -> &Self
+    where
+        R: Into<Request>,
+    {
+        self.0.request.set(request.into());
+        self.0.request_attributes.clear_data().await;
+        self
+    }
+
+    /// Gets the application state of a type registered with `Server::state`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The state if one of that type is registered.
+    pub async fn state<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.0
+            .state
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|value: Arc<dyn Any + Send + Sync>| value.downcast::<T>().ok())
+    }
+
+    /// Sets a data value in the request attributes.
     ///
     /// # Arguments
     ///
@@ -394,14 +768,11 @@ impl Context {
         K: Into<String>,
         V: Any + Send + Sync + Clone,
     {
-        self.write()
-            .await
-            .attributes
-            .insert(key.into(), Arc::new(value));
+        self.request().await.set_data(key, value).await;
         self
     }
 
-    /// Gets a data value from the context's data map.
+    /// Gets a data value from the request attributes.
     ///
     /// # Arguments
     ///
@@ -415,15 +786,10 @@ impl Context {
         V: Any + Send + Sync + Clone,
         K: AsRef<str>,
     {
-        self.read()
-            .await
-            .attributes
-            .get(key.as_ref())
-            .and_then(|arc| arc.downcast_ref::<V>())
-            .cloned()
+        self.request().await.try_get_data(key).await
     }
 
-    /// Gets a data value from the context's data map.
+    /// Gets a data value from the request attributes.
     ///
     /// # Arguments
     ///
@@ -441,10 +807,10 @@ impl Context {
         V: Any + Send + Sync + Clone,
         K: AsRef<str>,
     {
-        self.try_get_data(key).await.unwrap()
+        self.request().await.get_data_value(key).await
     }
 
-    /// Removes a data value from the context's data map.
+    /// Removes a data value from the request attributes.
     ///
     /// # Arguments
     ///
@@ -457,89 +823,461 @@ impl Context {
     where
         K: AsRef<str>,
     {
-        self.write().await.attributes.remove(key.as_ref());
+        self.request().await.remove_data(key).await;
         self
     }
 
-    /// Clears all data from the context's data map.
+    /// Clears all data from the request attributes, both string-keyed and type-keyed.
     ///
     /// # Returns
     ///
     /// - `&Self` - Reference to self for method chaining.
     pub async fn clear_data(&self) -> &Self {
-        self.write().await.attributes.clear();
+        self.request().await.clear_data().await;
         self
     }
 
-    /// Attempts to send data through the stream.
+    /// Stores a value keyed by its type, replacing any previous value of that type.
     ///
     /// # Arguments
     ///
-    /// - `D` - Data that can be converted to a byte slice.
+    /// - `T: Any + Send + Sync` - The value to store.
     ///
     /// # Returns
     ///
-    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
-    pub async fn try_send<D>(&self, data: D) -> ResponseResult
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn insert<T>(&self, value: T) -> &Self
     where
-        D: AsRef<[u8]>,
+        T: Any + Send + Sync,
     {
-        if self.is_terminated().await {
-            return Err(ResponseError::Terminated);
-        }
-        if let Some(stream) = self.try_get_stream().await {
-            return stream.try_send(data).await;
-        }
-        Err(ResponseError::NotFoundStream)
+        self.request().await.insert(value).await;
+        self
     }
 
-    /// Sends data through the stream.
-    ///
-    /// # Arguments
+    /// Gets a clone of the value stored for a type.
     ///
-    /// - `D` - Data that can be converted to a byte slice.
-    ///
-    /// # Panics
+    /// # Returns
     ///
-    /// Panics if the send operation fails.
-    pub async fn send<D>(&self, data: D)
+    /// - `Option<T>` - The value if one is stored.
+    pub async fn get<T>(&self) -> Option<T>
     where
-        D: AsRef<[u8]>,
+        T: Any + Send + Sync + Clone,
     {
-        self.try_send(data).await.unwrap();
+        self.request().await.get().await
     }
 
-    /// Attempts to flush the stream.
+    /// Gets a shared handle to the value stored for a type, which need not be `Clone`.
     ///
     /// # Returns
     ///
-    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
-    pub async fn try_flush(&self) -> ResponseResult {
-        if self.is_terminated().await {
-            return Err(ResponseError::Terminated);
-        }
-        if let Some(stream) = self.try_get_stream().await {
-            return stream.try_flush().await;
-        }
-        Err(ResponseError::NotFoundStream)
+    /// - `Option<Arc<T>>` - The value if one is stored.
+    pub async fn get_arc<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.get_arc().await
     }
 
-    /// Flushes the stream.
+    /// Runs a closure on a borrow of the value stored for a type.
     ///
-    /// # Panics
+    /// The request attributes stay read-locked while the closure runs.
     ///
-    /// Panics if the flush operation fails.
-    pub async fn flush(&self) {
+    /// # Arguments
+    ///
+    /// - `FnOnce(&T) -> R` - The closure to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<R>` - The closure result if a value is stored.
+    pub async fn with<T, F, R>(&self, func: F) -> Option<R>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&T) -> R,
+    {
+        self.request().await.with(func).await
+    }
+
+    /// Checks whether a value is stored for a type.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether a value is stored.
+    pub async fn contains<T>(&self) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.contains::<T>().await
+    }
+
+    /// Removes the value stored for a type.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The removed value, if any.
+    pub async fn remove<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.remove().await
+    }
+
+    /// Stores a value under a typed key.
+    ///
+    /// Typed keys have their own map, so a string key of the same name is a separate value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    /// - `T: Any + Send + Sync` - The value to store.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_key<T>(&self, key: &Key<T>, value: T) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.set_key(key, value).await;
+        self
+    }
+
+    /// Gets a clone of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<T>` - The value if one is stored.
+    pub async fn try_get_key<T>(&self, key: &Key<T>) -> Option<T>
+    where
+        T: Any + Send + Sync + Clone,
+    {
+        self.request().await.try_get_key(key).await
+    }
+
+    /// Gets a clone of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value is stored under the key.
+    pub async fn get_key<T>(&self, key: &Key<T>) -> T
+    where
+        T: Any + Send + Sync + Clone,
+    {
+        self.request().await.get_key(key).await
+    }
+
+    /// Gets a shared handle to the value stored under a typed key, which need not be `Clone`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The value if one is stored.
+    pub async fn try_get_key_arc<T>(&self, key: &Key<T>) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.try_get_key_arc(key).await
+    }
+
+    /// Runs a closure on a borrow of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    /// - `FnOnce(&T) -> R` - The closure to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<R>` - The closure result if a value is stored.
+    pub async fn with_key<T, F, R>(&self, key: &Key<T>, func: F) -> Option<R>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&T) -> R,
+    {
+        self.request().await.with_key(key, func).await
+    }
+
+    /// Removes the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn remove_key<T>(&self, key: &Key<T>) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.request().await.remove_key(key).await;
+        self
+    }
+
+    /// Waits until the response of this context is the one being written.
+    ///
+    /// Returns at once unless the context belongs to a pipelined connection.
+    async fn wait_response_turn(&self) {
+        if let Some(slot) = self.0.response_slot.get() {
+            slot.wait_turn().await;
+        }
+    }
+
+    /// Attempts to send data through the stream.
+    ///
+    /// On a pipelined connection the data is held while the responses of earlier
+    /// requests are still being written; on a multiplexed one it is written as one
+    /// frame tagged with the stream ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `D` - Data that can be converted to a byte slice.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub async fn try_send<D>(&self, data: D) -> ResponseResult
+    where
+        D: AsRef<[u8]>,
+    {
+        if self.is_terminated().await {
+            return Err(ResponseError::Terminated);
+        }
+        if let Some(slot) = self.0.response_slot.get() {
+            return slot.try_send_all([data]).await;
+        }
+        if let Some(stream) = self.try_get_stream().await {
+            if let Some(multiplex) = self.0.multiplex.get() {
+                return multiplex.try_send_all(&stream, [data]).await;
+            }
+            return stream.try_send(data).await;
+        }
+        Err(ResponseError::NotFoundStream)
+    }
+
+    /// Sends data through the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `D` - Data that can be converted to a byte slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the send operation fails.
+    pub async fn send<D>(&self, data: D)
+    where
+        D: AsRef<[u8]>,
+    {
+        self.try_send(data).await.unwrap();
+    }
+
+    /// Attempts to send several buffers through the stream with one vectored write.
+    ///
+    /// On a pipelined connection the buffers are held while the responses of earlier
+    /// requests are still being written; on a multiplexed one they are written as one
+    /// frame tagged with the stream ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = D>` - The buffers in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub async fn try_send_all<I, D>(&self, data: I) -> ResponseResult
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        if self.is_terminated().await {
+            return Err(ResponseError::Terminated);
+        }
+        if let Some(slot) = self.0.response_slot.get() {
+            return slot.try_send_all(data).await;
+        }
+        if let Some(stream) = self.try_get_stream().await {
+            if let Some(multiplex) = self.0.multiplex.get() {
+                return multiplex.try_send_all(&stream, data).await;
+            }
+            return stream.try_send_all(data).await;
+        }
+        Err(ResponseError::NotFoundStream)
+    }
+
+    /// Sends several buffers through the stream with one vectored write.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = D>` - The buffers in write order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the send operation fails.
+    pub async fn send_all<I, D>(&self, data: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        self.try_send_all(data).await.unwrap();
+    }
+
+    /// Attempts to send a byte range of a file through the stream.
+    ///
+    /// The file is not loaded into memory; see `ArcRwLockStream::try_send_file`. On a
+    /// multiplexed connection the range is read in `buffer_size` chunks, each written as
+    /// its own tagged frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<Path>` - The path of the file.
+    /// - `RangeBounds<u64>` - The byte range, clamped to the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of bytes sent, or an error on failure.
+    pub async fn try_send_file<P, R>(&self, path: P, range: R) -> TransferResult
+    where
+        P: AsRef<Path>,
+        R: RangeBounds<u64>,
+    {
+        if self.is_terminated().await {
+            return Err(ResponseError::Terminated);
+        }
+        let stream: ArcRwLockStream = self
+            .try_get_stream()
+            .await
+            .ok_or(ResponseError::NotFoundStream)?;
+        let file: ResponseFile = ResponseFile::open(path, range)
+            .map_err(|error: std::io::Error| ResponseError::FileError(error.into()))?;
+        if let Some(multiplex) = self.0.multiplex.get() {
+            return multiplex.try_send_file(&stream, file).await;
+        }
+        self.wait_response_turn().await;
+        stream.try_send_file(&file).await
+    }
+
+    /// Sends a byte range of a file through the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<Path>` - The path of the file.
+    /// - `RangeBounds<u64>` - The byte range, clamped to the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of bytes sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transfer fails.
+    pub async fn send_file<P, R>(&self, path: P, range: R) -> u64
+    where
+        P: AsRef<Path>,
+        R: RangeBounds<u64>,
+    {
+        self.try_send_file(path, range).await.unwrap()
+    }
+
+    /// Sends a streamed body through the stream with backpressure.
+    ///
+    /// Errors are reported in the returned `TransferReport` rather than panicking. On a
+    /// multiplexed connection every chunk is written as its own tagged frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStream` - The body to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferReport` - The bytes and chunks written and the error that stopped the transfer, if any.
+    pub async fn send_stream(&self, body: ResponseStream) -> TransferReport {
+        let error: ResponseError = if self.is_terminated().await {
+            ResponseError::Terminated
+        } else if let Some(stream) = self.try_get_stream().await {
+            if let Some(multiplex) = self.0.multiplex.get() {
+                return multiplex.send_stream(&stream, &body).await;
+            }
+            self.wait_response_turn().await;
+            return stream.send_stream(&body).await;
+        } else {
+            ResponseError::NotFoundStream
+        };
+        TransferReport {
+            error: Some(error),
+            ..Default::default()
+        }
+    }
+
+    /// Attempts to send a streamed body through the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStream` - The body to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of payload bytes sent, or the error that stopped the transfer.
+    pub async fn try_send_stream(&self, body: ResponseStream) -> TransferResult {
+        self.send_stream(body).await.into_result()
+    }
+
+    /// Attempts to flush the stream.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub async fn try_flush(&self) -> ResponseResult {
+        if self.is_terminated().await {
+            return Err(ResponseError::Terminated);
+        }
+        if let Some(stream) = self.try_get_stream().await {
+            return stream.try_flush().await;
+        }
+        Err(ResponseError::NotFoundStream)
+    }
+
+    /// Flushes the stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the flush operation fails.
+    pub async fn flush(&self) {
         self.try_flush().await.unwrap();
     }
 
     /// Attempts to shut down the stream.
     ///
+    /// On a multiplexed connection only the stream of the context is ended, with a frame
+    /// holding only its ID; the other streams keep the connection.
+    ///
     /// # Returns
     ///
     /// - `ResponseResult` - Ok(()) on success, or an error on failure.
     pub async fn try_shutdown(&self) -> ResponseResult {
         if let Some(stream) = self.try_get_stream().await {
+            if let Some(multiplex) = self.0.multiplex.get() {
+                if self.is_terminated().await {
+                    return Err(ResponseError::Terminated);
+                }
+                let result: ResponseResult = multiplex.end(&stream).await;
+                self.close().await;
+                return result;
+            }
+            self.wait_response_turn().await;
             let result: ResponseResult = stream.try_shutdown().await;
             self.close().await;
             return result;
@@ -556,3 +1294,452 @@ impl Context {
         self.try_shutdown().await.unwrap();
     }
 }
+
+impl<T> ContextCell<T> {
+    /// Creates a cell holding a value.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The initial value.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The cell.
+    #[inline(always)]
+    pub(crate) fn new(value: T) -> Self {
+        Self(std::sync::RwLock::new(value))
+    }
+
+    /// Gets a clone of the value.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The current value.
+    #[inline(always)]
+    pub(crate) fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the value.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The new value.
+    #[inline(always)]
+    pub(crate) fn set(&self, value: T) {
+        *self
+            .0
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = value;
+    }
+}
+
+impl<T> Key<T> {
+    /// Creates a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&'static str` - The attribute name the value is stored under.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The key.
+    #[inline(always)]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            marker: PhantomData,
+        }
+    }
+
+    /// Gets the attribute name the value is stored under.
+    ///
+    /// # Returns
+    ///
+    /// - `&'static str` - The attribute name.
+    #[inline(always)]
+    pub const fn get_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Key<T>
+where
+    T: Any,
+{
+    /// Gets the entry this key is stored under in the keyed value map.
+    ///
+    /// # Returns
+    ///
+    /// - `(&'static str, TypeId)` - The key name and value type.
+    #[inline(always)]
+    pub(crate) fn map_key(&self) -> (&'static str, TypeId) {
+        (self.name, TypeId::of::<T>())
+    }
+}
+
+/// Implementation of `Clone` for `Key<T>` regardless of `T`.
+impl<T> Clone for Key<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Key<T> {}
+
+/// Implementation of `Debug` for `Key<T>` regardless of `T`.
+impl<T> fmt::Debug for Key<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Key").field(&self.name).finish()
+    }
+}
+
+impl Attributes {
+    /// Creates an empty attribute scope.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Attributes instance.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acquires a read lock on the stored values.
+    ///
+    /// The lock is synchronous and must not be held across an `.await`.
+    ///
+    /// # Returns
+    ///
+    /// - `std::sync::RwLockReadGuard<AttributesData>` - The read guard.
+    pub(crate) fn read(&self) -> std::sync::RwLockReadGuard<'_, AttributesData> {
+        self.0
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Acquires a write lock on the stored values.
+    ///
+    /// The lock is synchronous and must not be held across an `.await`.
+    ///
+    /// # Returns
+    ///
+    /// - `std::sync::RwLockWriteGuard<AttributesData>` - The write guard.
+    pub(crate) fn write(&self) -> std::sync::RwLockWriteGuard<'_, AttributesData> {
+        self.0
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Sets a data value in the attribute map.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<String>` - The key for the data.
+    /// - `Any + Send + Sync + Clone` - The value to set, which must be cloneable and thread-safe.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_data<K, V>(&self, key: K, value: V) -> &Self
+    where
+        K: Into<String>,
+        V: Any + Send + Sync + Clone,
+    {
+        self.write().values.insert(key.into(), Arc::new(value));
+        self
+    }
+
+    /// Gets a data value from the attribute map.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The key for the data.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<V>` - The data value if found and successfully downcasted, otherwise `None`.
+    pub async fn try_get_data<V, K>(&self, key: K) -> Option<V>
+    where
+        V: Any + Send + Sync + Clone,
+        K: AsRef<str>,
+    {
+        self.read()
+            .values
+            .get(key.as_ref())
+            .and_then(|arc| arc.downcast_ref::<V>())
+            .cloned()
+    }
+
+    /// Gets a data value from the attribute map.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The key for the data.
+    ///
+    /// # Returns
+    ///
+    /// - `V` - The data value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is not found or cannot be downcasted.
+    pub async fn get_data_value<V, K>(&self, key: K) -> V
+    where
+        V: Any + Send + Sync + Clone,
+        K: AsRef<str>,
+    {
+        self.try_get_data(key).await.unwrap()
+    }
+
+    /// Removes a data value from the attribute map.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The key of the data to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn remove_data<K>(&self, key: K) -> &Self
+    where
+        K: AsRef<str>,
+    {
+        self.write().values.remove(key.as_ref());
+        self
+    }
+
+    /// Clears all data from the string-keyed and type-keyed values.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn clear_data(&self) -> &Self {
+        let mut data: std::sync::RwLockWriteGuard<'_, AttributesData> = self.write();
+        data.values.clear();
+        data.typed_values.clear();
+        data.keyed_values.clear();
+        self
+    }
+
+    /// Stores a value keyed by its type, replacing any previous value of that type.
+    ///
+    /// # Arguments
+    ///
+    /// - `T: Any + Send + Sync` - The value to store.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn insert<T>(&self, value: T) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.write()
+            .typed_values
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// Gets a clone of the value stored for a type.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<T>` - The value if one is stored.
+    pub async fn get<T>(&self) -> Option<T>
+    where
+        T: Any + Send + Sync + Clone,
+    {
+        self.with(T::clone).await
+    }
+
+    /// Gets a shared handle to the value stored for a type, which need not be `Clone`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The value if one is stored.
+    pub async fn get_arc<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.read()
+            .typed_values
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|value: Arc<dyn Any + Send + Sync>| value.downcast::<T>().ok())
+    }
+
+    /// Runs a closure on a borrow of the value stored for a type.
+    ///
+    /// The attributes stay read-locked while the closure runs.
+    ///
+    /// # Arguments
+    ///
+    /// - `FnOnce(&T) -> R` - The closure to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<R>` - The closure result if a value is stored.
+    pub async fn with<T, F, R>(&self, func: F) -> Option<R>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&T) -> R,
+    {
+        self.read()
+            .typed_values
+            .get(&TypeId::of::<T>())
+            .and_then(|value: &Arc<dyn Any + Send + Sync>| value.downcast_ref::<T>())
+            .map(func)
+    }
+
+    /// Checks whether a value is stored for a type.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether a value is stored.
+    pub async fn contains<T>(&self) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        self.read().typed_values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes the value stored for a type.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The removed value, if any.
+    pub async fn remove<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.write()
+            .typed_values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value: Arc<dyn Any + Send + Sync>| value.downcast::<T>().ok())
+    }
+
+    /// Stores a value under a typed key.
+    ///
+    /// Typed keys have their own map, so a string key of the same name is a separate value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    /// - `T: Any + Send + Sync` - The value to store.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_key<T>(&self, key: &Key<T>, value: T) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.write()
+            .keyed_values
+            .insert(key.map_key(), Arc::new(value));
+        self
+    }
+
+    /// Gets a clone of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<T>` - The value if one is stored.
+    pub async fn try_get_key<T>(&self, key: &Key<T>) -> Option<T>
+    where
+        T: Any + Send + Sync + Clone,
+    {
+        self.with_key(key, T::clone).await
+    }
+
+    /// Gets a clone of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value is stored under the key.
+    pub async fn get_key<T>(&self, key: &Key<T>) -> T
+    where
+        T: Any + Send + Sync + Clone,
+    {
+        self.try_get_key(key).await.unwrap()
+    }
+
+    /// Gets a shared handle to the value stored under a typed key, which need not be `Clone`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The value if one is stored.
+    pub async fn try_get_key_arc<T>(&self, key: &Key<T>) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.read()
+            .keyed_values
+            .get(&key.map_key())
+            .cloned()
+            .and_then(|value: Arc<dyn Any + Send + Sync>| value.downcast::<T>().ok())
+    }
+
+    /// Runs a closure on a borrow of the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    /// - `FnOnce(&T) -> R` - The closure to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<R>` - The closure result if a value is stored.
+    pub async fn with_key<T, F, R>(&self, key: &Key<T>, func: F) -> Option<R>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&T) -> R,
+    {
+        self.read()
+            .keyed_values
+            .get(&key.map_key())
+            .and_then(|value: &Arc<dyn Any + Send + Sync>| value.downcast_ref::<T>())
+            .map(func)
+    }
+
+    /// Removes the value stored under a typed key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Key<T>` - The typed key.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn remove_key<T>(&self, key: &Key<T>) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.write().keyed_values.remove(&key.map_key());
+        self
+    }
+}
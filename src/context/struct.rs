@@ -4,25 +4,80 @@ use super::*;
 ///
 /// This structure holds all the data associated with a single connection,
 /// including the stream, request, response, and any custom data.
-#[derive(Clone)]
+/// The identifier and server state never change and are read without locking, the flags
+/// are atomics, and every other field has its own short-lived synchronous lock.
 pub(crate) struct ContextData {
+    /// The process-unique identifier of the context.
+    pub(crate) id: u64,
     /// A flag indicating whether the connection handling has been aborted.
-    pub(crate) aborted: bool,
+    pub(crate) aborted: AtomicBool,
     /// A flag indicating whether the connection has been closed.
-    pub(crate) closed: bool,
+    pub(crate) closed: AtomicBool,
     /// The underlying network stream for the connection.
-    pub(crate) stream: Option<ArcRwLockStream>,
+    pub(crate) stream: ContextCell<Option<ArcRwLockStream>>,
+    /// The PROXY protocol header received from a trusted upstream.
+    pub(crate) proxy_header: ContextCell<Option<ProxyHeader>>,
+    /// The error that stopped the server from reading the request, given to read error handlers.
+    pub(crate) server_error: ContextCell<Option<ServerError>>,
+    /// The type name of the `ServerHook` currently running.
+    pub(crate) hook_name: ContextCell<Option<&'static str>>,
+    /// The hook panic, given to task panic handlers.
+    pub(crate) task_panic: ContextCell<Option<TaskPanic>>,
     /// The incoming request data.
-    pub(crate) request: Request,
+    pub(crate) request: ContextCell<Request>,
     /// The outgoing response.
-    pub(crate) response: Response,
-    /// Attributes storage for holding arbitrary type data during connection processing.
-    pub(crate) attributes: HashMapArcAnySendSync,
+    pub(crate) response: ContextCell<Response>,
+    /// The reader holding the bytes of the connection not yet handed out.
+    pub(crate) reader: ContextCell<Option<Arc<Mutex<ConnectionReader>>>>,
+    /// The place of the response in the connection's response order when pipelining.
+    pub(crate) response_slot: ContextCell<Option<ResponseSlot>>,
+    /// The stream of a multiplexed connection the context was created for.
+    pub(crate) multiplex: ContextCell<Option<MultiplexStream>>,
+    /// Attributes that persist for the lifetime of the connection.
+    pub(crate) connection_attributes: Attributes,
+    /// Attributes that are reset for each message read from the connection.
+    pub(crate) request_attributes: Attributes,
+    /// The application state registered on the server.
+    pub(crate) state: Arc<TypeMapArcAnySendSync>,
 }
 
+/// A mutable field of `ContextData`.
+///
+/// The lock is only held while the value is cloned or replaced, never across an `.await`.
+#[derive(Default)]
+pub(crate) struct ContextCell<T>(pub(crate) std::sync::RwLock<T>);
+
 /// The main connection context, providing thread-safe access to connection data.
 ///
-/// This is a wrapper around `ContextData` that uses an `Arc<RwLock<ContextData>>` to allow
-/// for shared, mutable access across asynchronous tasks.
+/// This is a wrapper around `ContextData` that uses an `Arc<ContextData>` to share
+/// the connection data across asynchronous tasks.
 #[derive(Clone)]
-pub struct Context(pub(super) ArcRwLock<ContextData>);
+pub struct Context(pub(super) Arc<ContextData>);
+
+/// A named attribute key bound to the type of its value.
+///
+/// Declaring keys as constants makes storing or reading a value of the wrong type
+/// a compile-time error.
+pub struct Key<T> {
+    /// The name the value is stored under, together with its type.
+    pub(crate) name: &'static str,
+    /// Binds the key to its value type.
+    pub(crate) marker: PhantomData<fn() -> T>,
+}
+
+/// The values stored in one attribute scope.
+#[derive(Clone, Default)]
+pub(crate) struct AttributesData {
+    /// Values stored under string keys.
+    pub(crate) values: HashMapArcAnySendSync,
+    /// Values stored once per type.
+    pub(crate) typed_values: TypeMapArcAnySendSync,
+    /// Values stored under typed keys, apart from the string keys.
+    pub(crate) keyed_values: KeyMapArcAnySendSync,
+}
+
+/// A shareable scope of attributes attached to a `Context`.
+///
+/// Clones refer to the same values.
+#[derive(Clone, Default)]
+pub struct Attributes(pub(super) Arc<std::sync::RwLock<AttributesData>>);
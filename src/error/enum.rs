@@ -1,14 +1,36 @@
+use super::*;
+
 /// Represents errors that can occur at the server level.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ServerError {
     /// An error occurred while trying to bind to a TCP socket.
-    TcpBind(String),
+    TcpBind(IoError),
+    /// An error occurred while accepting a connection on the TCP listener.
+    TcpAccept(IoError),
     /// An error occurred while reading from TCP stream.
-    TcpRead(String),
+    TcpRead(IoError),
     /// An error occurred while writing to TCP stream.
-    TcpWrite(String),
+    TcpWrite(IoError),
+    /// The peer reset or aborted the connection.
+    PeerReset(IoError),
+    /// The peer is no longer reading from the connection.
+    BrokenPipe(IoError),
+    /// The operation would block on a non-blocking socket.
+    WouldBlock(IoError),
+    /// An error occurred while handing listeners over to or from another process.
+    Handover(IoError),
+    /// The PROXY protocol header sent by a trusted upstream was missing or malformed.
+    ProxyProtocol(String),
+    /// A request grew beyond the configured maximum frame size.
+    FrameTooLarge(usize),
+    /// A frame of a multiplexed connection is shorter than the stream ID size.
+    MissingStreamId(usize),
+    /// The server configuration is invalid.
+    Config(ConfigError),
     /// The connection was closed unexpectedly.
     ConnectionClosed,
+    /// No data arrived within the read timeout.
+    ReadTimeout(Duration),
     /// An unknown or unexpected error occurred.
     Unknown(String),
 }
@@ -21,7 +43,52 @@ pub enum ResponseError {
     /// The connection has been terminated.
     Terminated,
     /// An error occurred while writing to the stream.
-    WriteError(String),
+    WriteError(IoError),
     /// An error occurred while flushing the stream.
-    FlushError(String),
+    FlushError(IoError),
+    /// An error occurred while opening or reading the file being sent.
+    FileError(IoError),
+    /// The source of a streamed response failed mid-stream.
+    StreamError(IoError),
+    /// The streamed response was already sent.
+    StreamConsumed,
+    /// The peer reset or aborted the connection.
+    PeerReset(IoError),
+    /// The peer is no longer reading from the connection.
+    BrokenPipe(IoError),
+    /// The operation would block on a non-blocking socket.
+    WouldBlock(IoError),
+}
+
+/// Represents errors raised while loading a server configuration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigError {
+    /// The configuration file could not be read.
+    Io(String),
+    /// The configuration file extension does not map to a supported format.
+    UnsupportedFormat(String),
+    /// The configuration text is not valid for its format.
+    Parse(String),
+    /// A field holds a value that cannot be used.
+    InvalidField {
+        /// The name of the offending field.
+        field: String,
+        /// A description of the problem.
+        message: String,
+    },
+    /// The host is neither an IP address nor a valid host name.
+    InvalidHost(String),
+    /// The read buffer size is zero.
+    ZeroBufferSize,
+    /// The maximum frame size is smaller than the read buffer.
+    MaxFrameTooSmall {
+        /// The configured maximum frame size.
+        max_frame_size: usize,
+        /// The configured read buffer size.
+        buffer_size: usize,
+    },
+    /// Two or more options cannot be used together.
+    ConflictingOptions(String),
+    /// The environment variable prefix is empty, so every variable would be read.
+    EmptyPrefix,
 }
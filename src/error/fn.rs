@@ -0,0 +1,31 @@
+use super::*;
+
+/// Maps connection-level I/O error kinds to the dedicated variants of an error type.
+///
+/// # Arguments
+///
+/// - `std::io::Error` - The error to classify.
+/// - `fn(IoError) -> E` - The variant for a connection reset or aborted by the peer.
+/// - `fn(IoError) -> E` - The variant for a broken pipe.
+/// - `fn(IoError) -> E` - The variant for an operation that would block.
+/// - `fn(IoError) -> E` - The variant used for any other kind.
+///
+/// # Returns
+///
+/// - `E` - The classified error.
+pub(crate) fn classify_io_error<E>(
+    error: std::io::Error,
+    peer_reset: fn(IoError) -> E,
+    broken_pipe: fn(IoError) -> E,
+    would_block: fn(IoError) -> E,
+    fallback: fn(IoError) -> E,
+) -> E {
+    match error.kind() {
+        std::io::ErrorKind::ConnectionReset | std::io::ErrorKind::ConnectionAborted => {
+            peer_reset(error.into())
+        }
+        std::io::ErrorKind::BrokenPipe => broken_pipe(error.into()),
+        std::io::ErrorKind::WouldBlock => would_block(error.into()),
+        _ => fallback(error.into()),
+    }
+}
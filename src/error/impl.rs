@@ -1,23 +1,51 @@
 use super::*;
 
 /// Implementation of `std::error::Error` for `ServerError`.
-impl std::error::Error for ServerError {}
+impl std::error::Error for ServerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Config(error) => Some(error),
+            _ => self
+                .try_get_io_error()
+                .map(|error: &IoError| error.get_inner() as &(dyn std::error::Error + 'static)),
+        }
+    }
+}
 
 /// Implementation of `Display` for `ServerError`.
 impl Display for ServerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::TcpBind(data) => write!(f, "Tcp bind error{COLON_SPACE}{data}"),
+            Self::TcpAccept(data) => write!(f, "Tcp accept error{COLON_SPACE}{data}"),
             Self::TcpRead(data) => write!(f, "Tcp read error{COLON_SPACE}{data}"),
             Self::TcpWrite(data) => write!(f, "Tcp write error{COLON_SPACE}{data}"),
+            Self::PeerReset(data) => write!(f, "Connection reset by peer{COLON_SPACE}{data}"),
+            Self::BrokenPipe(data) => write!(f, "Broken pipe{COLON_SPACE}{data}"),
+            Self::WouldBlock(data) => write!(f, "Operation would block{COLON_SPACE}{data}"),
+            Self::Handover(data) => write!(f, "Listener handover error{COLON_SPACE}{data}"),
+            Self::ProxyProtocol(data) => write!(f, "Proxy protocol error{COLON_SPACE}{data}"),
+            Self::FrameTooLarge(limit) => {
+                write!(f, "Frame too large{COLON_SPACE}exceeds {limit} bytes")
+            }
+            Self::MissingStreamId(size) => {
+                write!(f, "Frame is shorter than its {size}-byte stream ID")
+            }
+            Self::Config(error) => write!(f, "{error}"),
             Self::ConnectionClosed => write!(f, "Connection closed unexpectedly"),
+            Self::ReadTimeout(timeout) => write!(f, "No data received within {timeout:?}"),
             Self::Unknown(data) => write!(f, "Unknown error{COLON_SPACE}{data}"),
         }
     }
 }
 
 /// Implementation of `std::error::Error` for `ResponseError`.
-impl std::error::Error for ResponseError {}
+impl std::error::Error for ResponseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.try_get_io_error()
+            .map(|error: &IoError| error.get_inner() as &(dyn std::error::Error + 'static))
+    }
+}
 
 /// Implementation of `Display` for `ResponseError`.
 impl Display for ResponseError {
@@ -27,6 +55,241 @@ impl Display for ResponseError {
             Self::Terminated => write!(f, "Connection terminated"),
             Self::WriteError(data) => write!(f, "Write error{COLON_SPACE}{data}"),
             Self::FlushError(data) => write!(f, "Flush error{COLON_SPACE}{data}"),
+            Self::FileError(data) => write!(f, "File error{COLON_SPACE}{data}"),
+            Self::StreamError(data) => write!(f, "Stream error{COLON_SPACE}{data}"),
+            Self::StreamConsumed => write!(f, "Stream already consumed"),
+            Self::PeerReset(data) => write!(f, "Connection reset by peer{COLON_SPACE}{data}"),
+            Self::BrokenPipe(data) => write!(f, "Broken pipe{COLON_SPACE}{data}"),
+            Self::WouldBlock(data) => write!(f, "Operation would block{COLON_SPACE}{data}"),
+        }
+    }
+}
+
+/// Implementation of `std::error::Error` for `ConfigError`.
+impl std::error::Error for ConfigError {}
+
+/// Implementation of `Display` for `ConfigError`.
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(data) => write!(f, "Config read error{COLON_SPACE}{data}"),
+            Self::UnsupportedFormat(data) => {
+                write!(f, "Unsupported config format{COLON_SPACE}{data}")
+            }
+            Self::Parse(data) => write!(f, "Config parse error{COLON_SPACE}{data}"),
+            Self::InvalidField { field, message } => {
+                write!(f, "Invalid config field `{field}`{COLON_SPACE}{message}")
+            }
+            Self::InvalidHost(host) => write!(f, "Invalid host{COLON_SPACE}{host:?}"),
+            Self::ZeroBufferSize => write!(f, "Buffer size must be greater than zero"),
+            Self::MaxFrameTooSmall {
+                max_frame_size,
+                buffer_size,
+            } => write!(
+                f,
+                "Max frame size {max_frame_size} is smaller than buffer size {buffer_size}"
+            ),
+            Self::ConflictingOptions(data) => write!(f, "Conflicting options{COLON_SPACE}{data}"),
+            Self::EmptyPrefix => write!(f, "Config variable prefix must not be empty"),
+        }
+    }
+}
+
+/// Wraps an invalid configuration into a `ServerError`.
+impl From<ConfigError> for ServerError {
+    #[inline(always)]
+    fn from(error: ConfigError) -> Self {
+        Self::Config(error)
+    }
+}
+
+/// Converts a `ServerError` into an I/O error of the same kind, for `AsyncRead` consumers.
+impl From<ServerError> for std::io::Error {
+    fn from(error: ServerError) -> Self {
+        let kind: std::io::ErrorKind = error
+            .try_get_io_error_kind()
+            .unwrap_or(std::io::ErrorKind::Other);
+        Self::new(kind, error)
+    }
+}
+
+/// Wraps an I/O error so it can be shared and compared.
+impl From<std::io::Error> for IoError {
+    #[inline(always)]
+    fn from(error: std::io::Error) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+/// Compares I/O errors by kind, OS error code and message.
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_kind() == other.get_kind()
+            && self.try_get_raw_os_error() == other.try_get_raw_os_error()
+            && self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for IoError {}
+
+/// Implementation of `std::error::Error` for `IoError`.
+impl std::error::Error for IoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Implementation of `Display` for `IoError`.
+impl Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl IoError {
+    /// Gets the kind of the original error.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::ErrorKind` - The error kind.
+    #[inline(always)]
+    pub fn get_kind(&self) -> std::io::ErrorKind {
+        self.0.kind()
+    }
+
+    /// Gets the OS error code of the original error, if it came from the OS.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<i32>` - The raw OS error code.
+    #[inline(always)]
+    pub fn try_get_raw_os_error(&self) -> Option<i32> {
+        self.0.raw_os_error()
+    }
+
+    /// Gets the original error.
+    ///
+    /// # Returns
+    ///
+    /// - `&std::io::Error` - The original error.
+    #[inline(always)]
+    pub fn get_inner(&self) -> &std::io::Error {
+        &self.0
+    }
+}
+
+impl ServerError {
+    /// Classifies an error raised while reading from a connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::io::Error` - The error returned by the read.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - `PeerReset`, `BrokenPipe` or `WouldBlock` when the kind matches, `TcpRead` otherwise.
+    pub(crate) fn read(error: std::io::Error) -> Self {
+        classify_io_error(
+            error,
+            Self::PeerReset,
+            Self::BrokenPipe,
+            Self::WouldBlock,
+            Self::TcpRead,
+        )
+    }
+
+    /// Gets the I/O error this error was raised from.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&IoError>` - The I/O error, if any.
+    pub fn try_get_io_error(&self) -> Option<&IoError> {
+        match self {
+            Self::TcpBind(error)
+            | Self::TcpAccept(error)
+            | Self::TcpRead(error)
+            | Self::TcpWrite(error)
+            | Self::PeerReset(error)
+            | Self::BrokenPipe(error)
+            | Self::WouldBlock(error)
+            | Self::Handover(error) => Some(error),
+            _ => None,
         }
     }
+
+    /// Gets the kind of the I/O error this error was raised from.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<std::io::ErrorKind>` - The error kind, if any.
+    pub fn try_get_io_error_kind(&self) -> Option<std::io::ErrorKind> {
+        self.try_get_io_error().map(IoError::get_kind)
+    }
+}
+
+impl ResponseError {
+    /// Classifies an error raised while writing to a connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::io::Error` - The error returned by the write.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - `PeerReset`, `BrokenPipe` or `WouldBlock` when the kind matches, `WriteError` otherwise.
+    pub(crate) fn write(error: std::io::Error) -> Self {
+        classify_io_error(
+            error,
+            Self::PeerReset,
+            Self::BrokenPipe,
+            Self::WouldBlock,
+            Self::WriteError,
+        )
+    }
+
+    /// Classifies an error raised while flushing a connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::io::Error` - The error returned by the flush.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - `PeerReset`, `BrokenPipe` or `WouldBlock` when the kind matches, `FlushError` otherwise.
+    pub(crate) fn flush(error: std::io::Error) -> Self {
+        classify_io_error(
+            error,
+            Self::PeerReset,
+            Self::BrokenPipe,
+            Self::WouldBlock,
+            Self::FlushError,
+        )
+    }
+
+    /// Gets the I/O error this error was raised from.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&IoError>` - The I/O error, if any.
+    pub fn try_get_io_error(&self) -> Option<&IoError> {
+        match self {
+            Self::WriteError(error)
+            | Self::FlushError(error)
+            | Self::FileError(error)
+            | Self::StreamError(error)
+            | Self::PeerReset(error)
+            | Self::BrokenPipe(error)
+            | Self::WouldBlock(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Gets the kind of the I/O error this error was raised from.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<std::io::ErrorKind>` - The error kind, if any.
+    pub fn try_get_io_error_kind(&self) -> Option<std::io::ErrorKind> {
+        self.try_get_io_error().map(IoError::get_kind)
+    }
 }
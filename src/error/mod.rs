@@ -1,6 +1,10 @@
 mod r#enum;
+mod r#fn;
 mod r#impl;
+mod r#struct;
 
-pub use r#enum::*;
+pub use {r#enum::*, r#struct::*};
+
+pub(crate) use r#fn::*;
 
 use super::*;
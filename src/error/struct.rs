@@ -0,0 +1,8 @@
+use super::*;
+
+/// An I/O error shared between clones of the error that reported it.
+///
+/// The original `std::io::Error` is kept, so its kind and OS error code stay available
+/// and it is returned from `Error::source` of the enclosing error.
+#[derive(Clone, Debug)]
+pub struct IoError(pub(crate) Arc<std::io::Error>);
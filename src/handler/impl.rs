@@ -16,6 +16,7 @@ where
 {
     Arc::new(|ctx: Context| {
         Box::pin(async move {
+            ctx.set_hook_name(Some(type_name::<H>())).await;
             let hook: H = H::new(&ctx).await;
             hook.handle(&ctx).await;
         })
@@ -0,0 +1,420 @@
+use super::*;
+
+/// Guards the inherited descriptors so they are only wrapped once per process.
+static LISTEN_FDS_TAKEN: std::sync::Mutex<bool> = std::sync::Mutex::new(false);
+
+/// Marks a file descriptor as close-on-exec.
+///
+/// # Arguments
+///
+/// - `RawFd` - The file descriptor to update.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+fn set_cloexec(fd: RawFd) -> std::io::Result<()> {
+    let flags: i32 = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+    if flags < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    if unsafe { libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC) } < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Reads an integer socket option.
+///
+/// # Arguments
+///
+/// - `RawFd` - The socket to query.
+/// - `i32` - The option name at the `SOL_SOCKET` level.
+///
+/// # Returns
+///
+/// - `std::io::Result<i32>` - The option value, or the OS error on failure.
+fn get_socket_option(fd: RawFd, option: i32) -> std::io::Result<i32> {
+    let mut value: i32 = 0;
+    let mut len: libc::socklen_t = size_of::<i32>() as libc::socklen_t;
+    if unsafe {
+        libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            option,
+            (&mut value as *mut i32).cast(),
+            &mut len,
+        )
+    } < 0
+    {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(value)
+}
+
+/// Checks that a file descriptor is a listening stream socket.
+///
+/// # Arguments
+///
+/// - `RawFd` - The file descriptor to check.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Ok(()) if it is a listening stream socket, otherwise an error.
+fn check_listening_socket(fd: RawFd) -> std::io::Result<()> {
+    let not_listening = || {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("inherited descriptor {fd} is not a listening stream socket"),
+        )
+    };
+    if get_socket_option(fd, libc::SO_TYPE).map_err(|_| not_listening())? != libc::SOCK_STREAM {
+        return Err(not_listening());
+    }
+    if get_socket_option(fd, libc::SO_ACCEPTCONN)? == 0 {
+        return Err(not_listening());
+    }
+    Ok(())
+}
+
+/// Takes ownership of the listening sockets inherited through `LISTEN_FDS`.
+///
+/// The descriptors start at `LISTEN_FDS_START`. Nothing is taken unless `LISTEN_PID`
+/// matches the current process, and every descriptor must be a listening stream socket.
+/// Once taken, `LISTEN_FDS`, `LISTEN_PID` and `LISTEN_FDNAMES` are removed from the
+/// environment and subsequent calls return an empty list.
+///
+/// # Returns
+///
+/// - `std::io::Result<Vec<StdTcpListener>>` - The inherited listeners in non-blocking mode.
+pub fn take_listen_fds() -> std::io::Result<Vec<StdTcpListener>> {
+    Ok(take_named_listen_fds()?
+        .into_iter()
+        .map(|(_, listener): (String, StdTcpListener)| listener)
+        .collect())
+}
+
+/// Takes ownership of the inherited listening sockets together with their `LISTEN_FDNAMES` names.
+///
+/// Sockets without a name are reported as `DEFAULT_LISTEN_FD_NAME`.
+///
+/// # Returns
+///
+/// - `std::io::Result<Vec<(String, StdTcpListener)>>` - The named listeners in non-blocking mode.
+pub fn take_named_listen_fds() -> std::io::Result<Vec<(String, StdTcpListener)>> {
+    let mut taken: std::sync::MutexGuard<'_, bool> = LISTEN_FDS_TAKEN
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if *taken {
+        return Ok(Vec::new());
+    }
+    let pid: Option<u32> = std::env::var(LISTEN_PID_ENV)
+        .ok()
+        .and_then(|pid: String| pid.trim().parse().ok());
+    if pid != Some(std::process::id()) {
+        return Ok(Vec::new());
+    }
+    let count: usize = match std::env::var(LISTEN_FDS_ENV) {
+        Ok(count) => count.trim().parse().map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("invalid {LISTEN_FDS_ENV} value{COLON_SPACE}{count}"),
+            )
+        })?,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let names: String = std::env::var(LISTEN_FDNAMES_ENV).unwrap_or_default();
+    let mut names: std::str::Split<'_, &str> = names.split(COLON);
+    for offset in 0..count {
+        check_listening_socket(LISTEN_FDS_START + offset as RawFd)?;
+    }
+    let mut listeners: Vec<(String, StdTcpListener)> = Vec::with_capacity(count);
+    for offset in 0..count {
+        let fd: RawFd = LISTEN_FDS_START + offset as RawFd;
+        set_cloexec(fd)?;
+        let listener: StdTcpListener = unsafe { StdTcpListener::from_raw_fd(fd) };
+        listener.set_nonblocking(true)?;
+        let name: String = match names.next() {
+            Some(name) if !name.is_empty() => name.to_owned(),
+            _ => DEFAULT_LISTEN_FD_NAME.to_owned(),
+        };
+        listeners.push((name, listener));
+    }
+    unsafe {
+        std::env::remove_var(LISTEN_FDS_ENV);
+        std::env::remove_var(LISTEN_PID_ENV);
+        std::env::remove_var(LISTEN_FDNAMES_ENV);
+    }
+    *taken = true;
+    Ok(listeners)
+}
+
+/// Sends listening sockets to another process over a Unix domain socket.
+///
+/// # Arguments
+///
+/// - `&StdUnixStream` - The connected Unix socket to send the descriptors through.
+/// - `&[StdTcpListener]` - The listeners to transfer.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+pub fn send_listeners(socket: &StdUnixStream, listeners: &[StdTcpListener]) -> std::io::Result<()> {
+    if listeners.len() > MAX_HANDOVER_LISTENERS {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("at most {MAX_HANDOVER_LISTENERS} listeners can be handed over"),
+        ));
+    }
+    let fds: Vec<RawFd> = listeners.iter().map(AsRawFd::as_raw_fd).collect();
+    let fds_len: u32 = std::mem::size_of_val(fds.as_slice()) as u32;
+    let mut payload: [u8; 1] = [fds.len() as u8];
+    let mut iov: libc::iovec = libc::iovec {
+        iov_base: payload.as_mut_ptr().cast(),
+        iov_len: payload.len(),
+    };
+    let space: usize = unsafe { libc::CMSG_SPACE(fds_len) } as usize;
+    let mut control: Vec<u64> = vec![0; space.div_ceil(size_of::<u64>())];
+    let mut header: libc::msghdr = unsafe { std::mem::zeroed() };
+    header.msg_iov = &mut iov;
+    header.msg_iovlen = 1;
+    if !fds.is_empty() {
+        header.msg_control = control.as_mut_ptr().cast();
+        header.msg_controllen = space as _;
+        unsafe {
+            let cmsg: *mut libc::cmsghdr = libc::CMSG_FIRSTHDR(&header);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(fds_len) as _;
+            std::ptr::copy_nonoverlapping(
+                fds.as_ptr().cast::<u8>(),
+                libc::CMSG_DATA(cmsg),
+                fds_len as usize,
+            );
+        }
+    }
+    if unsafe { libc::sendmsg(socket.as_raw_fd(), &header, 0) } < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Receives listening sockets sent with `send_listeners`.
+///
+/// This call blocks until the sending process has written its message.
+///
+/// # Arguments
+///
+/// - `&StdUnixStream` - The connected Unix socket to receive the descriptors from.
+///
+/// # Returns
+///
+/// - `std::io::Result<Vec<StdTcpListener>>` - The received listeners in non-blocking mode.
+pub fn receive_listeners(socket: &StdUnixStream) -> std::io::Result<Vec<StdTcpListener>> {
+    let mut payload: [u8; 1] = [0];
+    let mut iov: libc::iovec = libc::iovec {
+        iov_base: payload.as_mut_ptr().cast(),
+        iov_len: payload.len(),
+    };
+    let space: usize =
+        unsafe { libc::CMSG_SPACE((MAX_HANDOVER_LISTENERS * size_of::<RawFd>()) as u32) } as usize;
+    let mut control: Vec<u64> = vec![0; space.div_ceil(size_of::<u64>())];
+    let mut header: libc::msghdr = unsafe { std::mem::zeroed() };
+    header.msg_iov = &mut iov;
+    header.msg_iovlen = 1;
+    header.msg_control = control.as_mut_ptr().cast();
+    header.msg_controllen = space as _;
+    let received: isize = unsafe { libc::recvmsg(socket.as_raw_fd(), &mut header, 0) };
+    if received < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    if received == 0 {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    let mut listeners: Vec<StdTcpListener> = Vec::new();
+    unsafe {
+        let mut cmsg: *mut libc::cmsghdr = libc::CMSG_FIRSTHDR(&header);
+        while !cmsg.is_null() {
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let data_len: usize = (*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize;
+                let data: *const RawFd = libc::CMSG_DATA(cmsg).cast();
+                for index in 0..data_len / size_of::<RawFd>() {
+                    let fd: RawFd = std::ptr::read_unaligned(data.add(index));
+                    listeners.push(StdTcpListener::from_raw_fd(fd));
+                }
+            }
+            cmsg = libc::CMSG_NXTHDR(&header, cmsg);
+        }
+    }
+    for listener in listeners.iter() {
+        set_cloexec(listener.as_raw_fd())?;
+        listener.set_nonblocking(true)?;
+    }
+    if header.msg_flags & libc::MSG_CTRUNC != 0 || listeners.len() != payload[0] as usize {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "incomplete listener handover message",
+        ));
+    }
+    Ok(listeners)
+}
+
+/// Prepares a command so the spawned process inherits the given listeners.
+///
+/// The listeners are placed at consecutive descriptors starting at `LISTEN_FDS_START`
+/// and announced through `LISTEN_FDS` and the child's own `LISTEN_PID`, so the child can
+/// pick them up with `take_listen_fds`. Any `LISTEN_FDNAMES` is cleared since the
+/// listeners carry no names.
+///
+/// The child's pid is only known after the fork, so the child is executed from a
+/// `pre_exec` hook with the current environment plus the variables set on the command.
+/// Configure the program, arguments and environment first and register no other
+/// `pre_exec` hook afterwards; `env_clear` is not honored.
+///
+/// # Arguments
+///
+/// - `&mut Command` - The command to prepare.
+/// - `Vec<StdTcpListener>` - The listeners to pass to the child.
+pub fn export_listen_fds(command: &mut Command, listeners: Vec<StdTcpListener>) {
+    stage_listen_fds(command, listeners, None);
+}
+
+/// Prepares a command so the spawned process inherits the given listeners under their names.
+///
+/// Works like `export_listen_fds` and also announces the names through `LISTEN_FDNAMES`,
+/// so the child can pick them up with `take_named_listen_fds`.
+///
+/// # Arguments
+///
+/// - `&mut Command` - The command to prepare.
+/// - `Vec<(String, StdTcpListener)>` - The named listeners to pass to the child.
+pub fn export_named_listen_fds(command: &mut Command, listeners: Vec<(String, StdTcpListener)>) {
+    let (names, listeners): (Vec<String>, Vec<StdTcpListener>) = listeners.into_iter().unzip();
+    stage_listen_fds(command, listeners, Some(names.join(COLON)));
+}
+
+/// Converts text to a C string.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The text.
+///
+/// # Returns
+///
+/// - `std::io::Result<CString>` - The C string, or an error if the text holds a NUL byte.
+fn to_c_string(value: &[u8]) -> std::io::Result<CString> {
+    CString::new(value).map_err(|error: std::ffi::NulError| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error)
+    })
+}
+
+/// Collects the program, arguments and environment changes the child is executed with.
+///
+/// # Arguments
+///
+/// - `&Command` - The command to read.
+/// - `usize` - The number of listeners passed.
+/// - `Option<String>` - The `LISTEN_FDNAMES` value, or `None` to clear it.
+///
+/// # Returns
+///
+/// - `std::io::Result<ListenExec>` - The exec parameters, or an error if any holds a NUL byte.
+fn listen_exec(
+    command: &Command,
+    count: usize,
+    names: Option<String>,
+) -> std::io::Result<ListenExec> {
+    let program: CString = to_c_string(command.get_program().as_bytes())?;
+    let mut args: Vec<CString> = vec![program.clone()];
+    for arg in command.get_args() {
+        args.push(to_c_string(arg.as_bytes())?);
+    }
+    let mut envs: Vec<(CString, Option<CString>)> = Vec::new();
+    for (key, value) in command.get_envs() {
+        envs.push((
+            to_c_string(key.as_bytes())?,
+            value
+                .map(|value: &std::ffi::OsStr| to_c_string(value.as_bytes()))
+                .transpose()?,
+        ));
+    }
+    envs.push((
+        to_c_string(LISTEN_FDS_ENV.as_bytes())?,
+        Some(to_c_string(count.to_string().as_bytes())?),
+    ));
+    envs.push((
+        to_c_string(LISTEN_FDNAMES_ENV.as_bytes())?,
+        names
+            .map(|names: String| to_c_string(names.as_bytes()))
+            .transpose()?,
+    ));
+    Ok(ListenExec {
+        program,
+        args,
+        envs,
+        pid_key: to_c_string(LISTEN_PID_ENV.as_bytes())?,
+    })
+}
+
+/// Places the listeners at `LISTEN_FDS_START` in the spawned process and executes it with the `LISTEN_*` variables set.
+///
+/// # Arguments
+///
+/// - `&mut Command` - The command to prepare.
+/// - `Vec<StdTcpListener>` - The listeners to pass to the child.
+/// - `Option<String>` - The `LISTEN_FDNAMES` value, or `None` to clear it.
+fn stage_listen_fds(command: &mut Command, listeners: Vec<StdTcpListener>, names: Option<String>) {
+    let count: usize = listeners.len();
+    let mut staged: Vec<RawFd> = vec![-1; count];
+    let exec: Option<ListenExec> = listen_exec(command, count, names).ok();
+    unsafe {
+        command.pre_exec(move || {
+            let Some(exec) = exec.as_ref() else {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "command holds a NUL byte",
+                ));
+            };
+            let floor: RawFd = LISTEN_FDS_START + count as RawFd;
+            for (index, listener) in listeners.iter().enumerate() {
+                let fd: RawFd = libc::fcntl(listener.as_raw_fd(), libc::F_DUPFD_CLOEXEC, floor);
+                if fd < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                staged[index] = fd;
+            }
+            for (index, fd) in staged.iter().enumerate() {
+                if libc::dup2(*fd, LISTEN_FDS_START + index as RawFd) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            for (key, value) in exec.envs.iter() {
+                let result: i32 = match value {
+                    Some(value) => libc::setenv(key.as_ptr(), value.as_ptr(), 1),
+                    None => libc::unsetenv(key.as_ptr()),
+                };
+                if result < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            let mut pid: u32 = libc::getpid() as u32;
+            let mut digits: [u8; 11] = [0; 11];
+            let mut start: usize = digits.len() - 1;
+            loop {
+                start -= 1;
+                digits[start] = b'0' + (pid % 10) as u8;
+                pid /= 10;
+                if pid == 0 {
+                    break;
+                }
+            }
+            if libc::setenv(exec.pid_key.as_ptr(), digits[start..].as_ptr().cast(), 1) < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let mut argv: Vec<*const libc::c_char> =
+                exec.args.iter().map(|arg: &CString| arg.as_ptr()).collect();
+            argv.push(std::ptr::null());
+            libc::execvp(exec.program.as_ptr(), argv.as_ptr());
+            Err(std::io::Error::last_os_error())
+        });
+    }
+}
@@ -0,0 +1,8 @@
+mod r#fn;
+mod r#struct;
+
+pub use r#fn::*;
+
+pub(crate) use r#struct::*;
+
+use super::*;
@@ -0,0 +1,15 @@
+use super::*;
+
+/// Holds what a child inheriting listeners is executed with.
+///
+/// Collected before the fork, so the `pre_exec` hook only copies pointers.
+pub(crate) struct ListenExec {
+    /// The program to execute.
+    pub(crate) program: CString,
+    /// The arguments, starting with the program.
+    pub(crate) args: Vec<CString>,
+    /// The variables to set, or to remove when the value is `None`.
+    pub(crate) envs: Vec<(CString, Option<CString>)>,
+    /// The name of the variable the child's pid is stored in.
+    pub(crate) pid_key: CString,
+}
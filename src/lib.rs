@@ -12,33 +12,82 @@ mod config;
 mod context;
 mod error;
 mod handler;
+#[cfg(unix)]
+mod handover;
+mod multiplex;
+mod panic;
+mod pool;
+mod proxy;
 mod request;
 mod response;
 mod server;
 mod stream;
+#[cfg(unix)]
+mod systemd;
 mod utils;
 
 pub use {
-    common::*, config::*, context::*, error::*, handler::*, request::*, response::*, server::*,
-    stream::*, utils::*,
+    common::*, config::*, context::*, error::*, handler::*, panic::*, pool::*, proxy::*,
+    request::*, response::*, server::*, stream::*, utils::*,
 };
 
-pub use tokio;
+#[cfg(unix)]
+pub use {handover::*, systemd::*};
+
+pub use {bytes, futures_core, tokio};
+
+use multiplex::*;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures_core::Stream;
 
 use std::{
-    any::Any,
-    collections::HashMap,
+    any::{Any, TypeId, type_name},
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
     fmt::{self, Display},
     future::Future,
-    net::SocketAddr,
-    pin::Pin,
-    sync::Arc,
+    io::IoSlice,
+    marker::PhantomData,
+    net::{IpAddr, Ipv6Addr, SocketAddr, TcpListener as StdTcpListener},
+    ops::{Bound, RangeBounds},
+    path::Path,
+    pin::{Pin, pin},
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+#[cfg(unix)]
+use std::{
+    ffi::CString,
+    os::{
+        fd::{AsRawFd, FromRawFd, RawFd},
+        unix::{
+            ffi::OsStrExt,
+            net::{UnixDatagram as StdUnixDatagram, UnixStream as StdUnixStream},
+            process::CommandExt,
+        },
+    },
+    process::{Child, Command},
 };
 
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
+    io::{AsyncRead, AsyncWriteExt, Interest, ReadBuf},
     net::{TcpListener, TcpStream},
     spawn,
-    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, watch::Sender, watch::channel},
-    task::{JoinError, JoinHandle},
+    sync::{
+        Mutex, MutexGuard, Notify, OwnedSemaphorePermit, RwLock, RwLockReadGuard, RwLockWriteGuard,
+        Semaphore,
+        futures::Notified,
+        mpsc::{self, UnboundedReceiver, UnboundedSender, error::SendError, unbounded_channel},
+        watch::Receiver,
+        watch::Sender,
+        watch::channel,
+    },
+    task::{AbortHandle, JoinError, JoinHandle, JoinSet},
 };
@@ -0,0 +1,22 @@
+use super::*;
+
+/// Splits the big-endian stream ID off the front of a frame.
+///
+/// # Arguments
+///
+/// - `&mut Request` - The frame, left holding the payload.
+/// - `usize` - The size in bytes of the ID.
+///
+/// # Returns
+///
+/// - `Option<u64>` - The stream ID, or `None` if the frame is shorter than the ID.
+pub(crate) fn split_stream_id(frame: &mut Request, id_size: usize) -> Option<u64> {
+    if frame.len() < id_size {
+        return None;
+    }
+    let id: Request = frame.split_to(id_size);
+    Some(
+        id.iter()
+            .fold(0, |id: u64, byte: &u8| (id << 8) | u64::from(*byte)),
+    )
+}
@@ -0,0 +1,200 @@
+use super::*;
+
+impl MultiplexStream {
+    /// Waits for the next frame of the stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Request>` - The payload of the frame, or `None` once the stream was
+    ///   cancelled or the connection has no more frames.
+    pub(crate) async fn next_frame(&self) -> Option<Request> {
+        self.frames.lock().await.recv().await
+    }
+
+    /// Stops accepting frames, so later frames with the same ID open a new stream.
+    pub(crate) async fn close(&self) {
+        self.frames.lock().await.close();
+    }
+
+    /// Ends the stream by writing a frame holding only its ID.
+    ///
+    /// The stream stops accepting frames; the connection stays open for the other streams.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream` - The stream of the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub(crate) async fn end(&self, stream: &ArcRwLockStream) -> ResponseResult {
+        self.close().await;
+        let payload: [&[u8]; 0] = [];
+        self.try_send_all(stream, payload).await
+    }
+
+    /// Writes buffers as one frame tagged with the stream ID.
+    ///
+    /// The frame is written by its own task, so aborting the hooks of the stream never
+    /// leaves a partial frame on the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream` - The stream of the connection.
+    /// - `I: IntoIterator<Item = D>` - The payload buffers in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub(crate) async fn try_send_all<I, D>(
+        &self,
+        stream: &ArcRwLockStream,
+        data: I,
+    ) -> ResponseResult
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        let payload: Vec<D> = data.into_iter().collect();
+        let id: [u8; MAX_STREAM_ID_SIZE] = self.id.to_be_bytes();
+        let id: &[u8] = &id[MAX_STREAM_ID_SIZE - self.id_size..];
+        let len: usize = id.len()
+            + payload
+                .iter()
+                .map(|buffer: &D| buffer.as_ref().len())
+                .sum::<usize>();
+        let prefix: Vec<u8> = match self.framing {
+            Framing::LengthPrefixed => u32::try_from(len)
+                .map_err(|_| {
+                    ResponseError::write(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "frame does not fit a u32 length prefix",
+                    ))
+                })?
+                .to_be_bytes()
+                .to_vec(),
+            _ => Vec::new(),
+        };
+        let suffix: &[u8] = match &self.framing {
+            Framing::Auto | Framing::Terminated => SPLIT_REQUEST_BYTES,
+            Framing::Delimiter(delimiter) => delimiter,
+            Framing::LengthPrefixed | Framing::Raw => b"",
+        };
+        let mut frame: BytesMut = BytesMut::with_capacity(prefix.len() + len + suffix.len());
+        frame.put_slice(&prefix);
+        frame.put_slice(id);
+        for buffer in &payload {
+            frame.put_slice(buffer.as_ref());
+        }
+        frame.put_slice(suffix);
+        let frame: ResponseData = frame.freeze();
+        let stream: ArcRwLockStream = stream.clone();
+        spawn(async move { stream.try_send(&frame).await })
+            .await
+            .map_err(|error: JoinError| ResponseError::write(std::io::Error::other(error)))?
+    }
+
+    /// Writes a file range as frames of up to `buffer_size` bytes tagged with the stream ID.
+    ///
+    /// The range is read on the blocking pool into a bounded queue, so only a few chunks
+    /// are held in memory however large the range is.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream` - The stream of the connection.
+    /// - `ResponseFile` - The file range to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of payload bytes sent, or an error on failure.
+    pub(crate) async fn try_send_file(
+        &self,
+        stream: &ArcRwLockStream,
+        file: ResponseFile,
+    ) -> TransferResult {
+        let len: u64 = file.len;
+        let chunk_size: u64 = self.chunk_size as u64;
+        let (sender, mut receiver) =
+            mpsc::channel::<std::io::Result<ResponseData>>(STREAM_FRAME_QUEUE_CAPACITY);
+        tokio::task::spawn_blocking(move || {
+            let mut read: u64 = 0;
+            while read < file.len {
+                let size: usize = (file.len - read).min(chunk_size) as usize;
+                let chunk: std::io::Result<ResponseData> =
+                    match read_file_at(&file.file, file.offset + read, size) {
+                        Ok(chunk) if chunk.is_empty() => {
+                            Err(std::io::ErrorKind::UnexpectedEof.into())
+                        }
+                        Ok(chunk) => Ok(chunk.into()),
+                        Err(error) => Err(error),
+                    };
+                let failed: bool = chunk.is_err();
+                read += chunk
+                    .as_ref()
+                    .map_or(0, |chunk: &ResponseData| chunk.len() as u64);
+                if sender.blocking_send(chunk).is_err() || failed {
+                    return;
+                }
+            }
+        });
+        let mut sent: u64 = 0;
+        while let Some(chunk) = receiver.recv().await {
+            let chunk: ResponseData =
+                chunk.map_err(|error: std::io::Error| ResponseError::FileError(error.into()))?;
+            self.try_send_all(stream, [&chunk]).await?;
+            sent += chunk.len() as u64;
+        }
+        if sent < len {
+            return Err(ResponseError::FileError(
+                std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into(),
+            ));
+        }
+        Ok(sent)
+    }
+
+    /// Sends every chunk of a streamed body as its own frame tagged with the stream ID.
+    ///
+    /// The chunk framing of the body is not used.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream` - The stream of the connection.
+    /// - `&ResponseStream` - The body to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferReport` - The bytes and chunks written and the error that stopped the transfer, if any.
+    pub(crate) async fn send_stream(
+        &self,
+        stream: &ArcRwLockStream,
+        body: &ResponseStream,
+    ) -> TransferReport {
+        let mut report: TransferReport = TransferReport::default();
+        let Some(mut source) = body.source.lock().await.take() else {
+            report.error = Some(ResponseError::StreamConsumed);
+            return report;
+        };
+        while let Some(item) =
+            std::future::poll_fn(|cx: &mut std::task::Context<'_>| source.as_mut().poll_next(cx))
+                .await
+        {
+            let chunk: ResponseData = match item {
+                Ok(chunk) => chunk,
+                Err(error) => {
+                    report.error = Some(ResponseError::StreamError(error.into()));
+                    return report;
+                }
+            };
+            if chunk.is_empty() {
+                continue;
+            }
+            if let Err(error) = self.try_send_all(stream, [&chunk]).await {
+                report.error = Some(error);
+                return report;
+            }
+            report.bytes += chunk.len() as u64;
+            report.chunks += 1;
+        }
+        report
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+/// A stream of a multiplexed connection, as seen by its context.
+///
+/// Every send is written as one frame tagged with the stream ID, so the responses
+/// of concurrent streams interleave on the socket frame by frame.
+#[derive(Clone)]
+pub(crate) struct MultiplexStream {
+    /// The ID the frames of the stream are tagged with.
+    pub(crate) id: u64,
+    /// The size in bytes of the ID on the wire.
+    pub(crate) id_size: usize,
+    /// How the tagged frames are delimited.
+    pub(crate) framing: Framing,
+    /// The largest payload of the frames a file range is split into.
+    pub(crate) chunk_size: usize,
+    /// The frames received for the stream after the one that opened it.
+    pub(crate) frames: Arc<Mutex<mpsc::Receiver<Request>>>,
+}
+
+/// A running stream of a multiplexed connection, as tracked by the server.
+pub(crate) struct MultiplexEntry {
+    /// Hands later frames of the stream to its context.
+    pub(crate) frames: mpsc::Sender<Request>,
+    /// The context the hooks of the stream run with.
+    pub(crate) ctx: Context,
+    /// Aborts the task running the hooks of the stream.
+    pub(crate) task: AbortHandle,
+}
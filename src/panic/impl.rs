@@ -0,0 +1,89 @@
+use super::*;
+
+impl TaskPanic {
+    /// Creates a panic description from the payload of a panicked task.
+    ///
+    /// # Arguments
+    ///
+    /// - `Box<dyn Any + Send>` - The panic payload.
+    /// - `usize` - The position of the failing hook.
+    /// - `Option<&'static str>` - The type name of the failing hook.
+    /// - `Duration` - How long the hook ran.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The panic description.
+    pub(crate) fn new(
+        payload: Box<dyn Any + Send>,
+        hook_index: usize,
+        hook_name: Option<&'static str>,
+        elapsed: Duration,
+    ) -> Self {
+        let message: Option<String> = match payload.downcast::<String>() {
+            Ok(message) => Some(*message),
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map(|message: &&str| (*message).to_owned()),
+        };
+        Self {
+            message,
+            hook_index,
+            hook_name,
+            elapsed,
+        }
+    }
+
+    /// Gets the panic message.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The message, when the payload is a `&str` or `String`.
+    #[inline(always)]
+    pub fn try_get_message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Gets the position of the failing hook in the hook list.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The zero-based hook index.
+    #[inline(always)]
+    pub fn get_hook_index(&self) -> usize {
+        self.hook_index
+    }
+
+    /// Gets the type name of the failing hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static str>` - The type name, when the hook was registered as a `ServerHook`.
+    #[inline(always)]
+    pub fn try_get_hook_name(&self) -> Option<&'static str> {
+        self.hook_name
+    }
+
+    /// Gets how long the failing hook ran before it panicked.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The elapsed time.
+    #[inline(always)]
+    pub fn get_elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Implementation of `Display` for `TaskPanic`.
+impl Display for TaskPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Hook {} ({}) panicked after {:?}{COLON_SPACE}{}",
+            self.hook_index,
+            self.hook_name.unwrap_or("unknown"),
+            self.elapsed,
+            self.message.as_deref().unwrap_or("Box<dyn Any>")
+        )
+    }
+}
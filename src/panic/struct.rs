@@ -0,0 +1,16 @@
+use super::*;
+
+/// Describes a hook that panicked while processing a connection.
+///
+/// It is set on the context given to `task_panic` handlers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskPanic {
+    /// The panic message, when the payload is a `&str` or `String`.
+    pub(crate) message: Option<String>,
+    /// The position of the failing hook in the hook list.
+    pub(crate) hook_index: usize,
+    /// The type name of the failing hook, when it was registered as a `ServerHook`.
+    pub(crate) hook_name: Option<&'static str>,
+    /// How long the failing hook ran before it panicked.
+    pub(crate) elapsed: Duration,
+}
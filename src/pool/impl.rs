@@ -0,0 +1,241 @@
+use super::*;
+
+/// Provides a default implementation for BufferPool.
+impl Default for BufferPool {
+    /// Creates a pool without size classes, which allocates every buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new BufferPool instance.
+    fn default() -> Self {
+        Self::new([], 0)
+    }
+}
+
+impl BufferPool {
+    /// Creates a pool with the given size classes.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = usize>` - The buffer capacities to pool; zero sizes are ignored.
+    /// - `usize` - The maximum number of idle buffers kept per class.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new BufferPool instance.
+    pub fn new<I>(classes: I, capacity: usize) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut sizes: Vec<usize> = classes
+            .into_iter()
+            .filter(|size: &usize| *size > 0)
+            .collect();
+        sizes.sort_unstable();
+        sizes.dedup();
+        Self(Arc::new(BufferPoolData {
+            classes: sizes
+                .into_iter()
+                .map(|size: usize| BufferClass {
+                    size,
+                    free: std::sync::Mutex::new(Vec::new()),
+                })
+                .collect(),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            returned: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+        }))
+    }
+
+    /// Gets the pooled buffer capacities, from smallest to largest.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<usize>` - The size classes.
+    pub fn get_classes(&self) -> Vec<usize> {
+        self.0
+            .classes
+            .iter()
+            .map(|class: &BufferClass| class.size)
+            .collect()
+    }
+
+    /// Gets the maximum number of idle buffers kept per class.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The per-class cap.
+    pub fn get_capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Borrows an empty buffer able to hold at least `min_size` bytes.
+    ///
+    /// The buffer comes from the smallest class that fits. Requests larger than every
+    /// class are allocated and not returned to the pool.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The minimum capacity.
+    ///
+    /// # Returns
+    ///
+    /// - `PooledBuffer` - The borrowed buffer.
+    pub fn acquire(&self, min_size: usize) -> PooledBuffer {
+        let class: Option<usize> = self
+            .0
+            .classes
+            .iter()
+            .position(|class: &BufferClass| class.size >= min_size);
+        let reused: Option<BytesMut> = class.and_then(|index: usize| {
+            self.0.classes[index]
+                .free
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .pop()
+        });
+        let buffer: BytesMut = match reused {
+            Some(buffer) => {
+                self.0.hits.fetch_add(1, Ordering::Relaxed);
+                buffer
+            }
+            None => {
+                self.0.misses.fetch_add(1, Ordering::Relaxed);
+                let size: usize = class.map_or(min_size, |index: usize| self.0.classes[index].size);
+                BytesMut::with_capacity(size)
+            }
+        };
+        PooledBuffer {
+            buffer,
+            class,
+            pool: self.clone(),
+        }
+    }
+
+    /// Takes a snapshot of the pool counters.
+    ///
+    /// # Returns
+    ///
+    /// - `BufferPoolStats` - The snapshot of the counters.
+    pub fn stats(&self) -> BufferPoolStats {
+        BufferPoolStats {
+            hits: self.0.hits.load(Ordering::Relaxed),
+            misses: self.0.misses.load(Ordering::Relaxed),
+            returned: self.0.returned.load(Ordering::Relaxed),
+            discarded: self.0.discarded.load(Ordering::Relaxed),
+            idle: self
+                .0
+                .classes
+                .iter()
+                .map(|class: &BufferClass| {
+                    class
+                        .free
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .len()
+                })
+                .sum(),
+        }
+    }
+
+    /// Puts a buffer back into its class, or drops it when it cannot be reused.
+    ///
+    /// Memory still shared with requests split off the buffer is not reclaimed.
+    ///
+    /// # Arguments
+    ///
+    /// - `BytesMut` - The buffer to return.
+    /// - `usize` - The index of its size class.
+    fn release(&self, mut buffer: BytesMut, index: usize) {
+        let class: &BufferClass = &self.0.classes[index];
+        buffer.clear();
+        if buffer.capacity() < class.size && !buffer.try_reclaim(class.size) {
+            self.0.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        let mut free: std::sync::MutexGuard<'_, Vec<BytesMut>> = class
+            .free
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if free.len() >= self.0.capacity {
+            self.0.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        free.push(buffer);
+        self.0.returned.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Returns the buffer to its pool.
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        if let Some(index) = self.class {
+            self.pool.release(std::mem::take(&mut self.buffer), index);
+        }
+    }
+}
+
+/// Dereferences a `PooledBuffer` to its `BytesMut`.
+impl std::ops::Deref for PooledBuffer {
+    type Target = BytesMut;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+/// Mutably dereferences a `PooledBuffer` to its `BytesMut`.
+impl std::ops::DerefMut for PooledBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl BufferPoolStats {
+    /// Gets the number of requests served from an idle buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of hits.
+    pub fn get_hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Gets the number of requests that had to allocate.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of misses.
+    pub fn get_misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Gets the number of buffers put back for reuse.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of returned buffers.
+    pub fn get_returned(&self) -> u64 {
+        self.returned
+    }
+
+    /// Gets the number of buffers dropped instead of being reused.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of discarded buffers.
+    pub fn get_discarded(&self) -> u64 {
+        self.discarded
+    }
+
+    /// Gets the number of idle buffers currently held.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of idle buffers.
+    pub fn get_idle(&self) -> usize {
+        self.idle
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+/// The idle buffers of one size class.
+pub(crate) struct BufferClass {
+    /// The capacity of every buffer in the class.
+    pub(crate) size: usize,
+    /// The buffers waiting to be reused.
+    pub(crate) free: std::sync::Mutex<Vec<BytesMut>>,
+}
+
+/// The shared state of a `BufferPool`.
+pub(crate) struct BufferPoolData {
+    /// The size classes, ordered from smallest to largest.
+    pub(crate) classes: Vec<BufferClass>,
+    /// The maximum number of idle buffers kept per class.
+    pub(crate) capacity: usize,
+    /// The number of requests served from an idle buffer.
+    pub(crate) hits: AtomicU64,
+    /// The number of requests that had to allocate.
+    pub(crate) misses: AtomicU64,
+    /// The number of buffers put back for reuse.
+    pub(crate) returned: AtomicU64,
+    /// The number of buffers dropped because the class was full or the memory was still shared.
+    pub(crate) discarded: AtomicU64,
+}
+
+/// A pool of read buffers grouped into size classes.
+///
+/// Buffers are handed out without being zero-filled and go back to the pool when
+/// the `PooledBuffer` is dropped. Clones refer to the same pool.
+#[derive(Clone)]
+pub struct BufferPool(pub(crate) Arc<BufferPoolData>);
+
+/// A buffer borrowed from a `BufferPool`.
+///
+/// It dereferences to `BytesMut` and returns to its pool when dropped.
+pub struct PooledBuffer {
+    /// The borrowed buffer.
+    pub(crate) buffer: BytesMut,
+    /// The index of the size class the buffer belongs to, or `None` when it is larger than every class.
+    pub(crate) class: Option<usize>,
+    /// The pool the buffer returns to.
+    pub(crate) pool: BufferPool,
+}
+
+/// A point-in-time snapshot of the buffer pool counters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BufferPoolStats {
+    /// The number of requests served from an idle buffer.
+    pub(crate) hits: u64,
+    /// The number of requests that had to allocate.
+    pub(crate) misses: u64,
+    /// The number of buffers put back for reuse.
+    pub(crate) returned: u64,
+    /// The number of buffers dropped instead of being reused.
+    pub(crate) discarded: u64,
+    /// The number of idle buffers currently held.
+    pub(crate) idle: usize,
+}
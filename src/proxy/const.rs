@@ -0,0 +1,11 @@
+/// The signature that starts a PROXY protocol v1 header.
+pub const PROXY_V1_SIGNATURE: &[u8] = b"PROXY";
+
+/// The signature that starts a PROXY protocol v2 header.
+pub const PROXY_V2_SIGNATURE: &[u8] = b"\r\n\r\n\0\r\nQUIT\n";
+
+/// The maximum length of a PROXY protocol v1 header, including the trailing CRLF.
+pub const PROXY_V1_MAX_LENGTH: usize = 107;
+
+/// The length of the fixed part of a PROXY protocol v2 header.
+pub const PROXY_V2_HEADER_LENGTH: usize = 16;
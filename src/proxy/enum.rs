@@ -0,0 +1,27 @@
+use super::*;
+
+/// The PROXY protocol version a header was encoded with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProxyVersion {
+    /// The human-readable text format.
+    V1,
+    /// The binary format, which can carry TLVs.
+    V2,
+}
+
+/// The command carried by a PROXY protocol header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProxyCommand {
+    /// The connection was opened by the proxy itself, for example a health check.
+    Local,
+    /// The connection was relayed on behalf of a client.
+    Proxy,
+}
+
+/// The progress of decoding a PROXY protocol header from a partial buffer.
+pub(crate) enum ProxyDecodeState {
+    /// The header is complete and spans the given number of bytes.
+    Complete(ProxyHeader, usize),
+    /// At least the given number of additional bytes are needed.
+    Incomplete(usize),
+}
@@ -0,0 +1,459 @@
+use super::*;
+
+impl ProxyTlv {
+    /// Gets the record type.
+    ///
+    /// # Returns
+    ///
+    /// - `u8` - The record type.
+    pub fn get_kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// Gets the raw record value.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The record value.
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl ProxyHeader {
+    /// Gets the protocol version the header was encoded with.
+    ///
+    /// # Returns
+    ///
+    /// - `ProxyVersion` - The protocol version.
+    pub fn get_version(&self) -> ProxyVersion {
+        self.version
+    }
+
+    /// Gets the command carried by the header.
+    ///
+    /// # Returns
+    ///
+    /// - `ProxyCommand` - The header command.
+    pub fn get_command(&self) -> ProxyCommand {
+        self.command
+    }
+
+    /// Gets the original client address.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionSocketAddr` - The client address if the header carried one.
+    pub fn get_source(&self) -> OptionSocketAddr {
+        self.source
+    }
+
+    /// Gets the original destination address.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionSocketAddr` - The destination address if the header carried one.
+    pub fn get_destination(&self) -> OptionSocketAddr {
+        self.destination
+    }
+
+    /// Gets the TLV records of a v2 header.
+    ///
+    /// # Returns
+    ///
+    /// - `&[ProxyTlv]` - The TLV records, empty for v1 headers.
+    pub fn get_tlvs(&self) -> &[ProxyTlv] {
+        &self.tlvs
+    }
+
+    /// Finds the first TLV record of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The record type.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&ProxyTlv>` - The record if present.
+    pub fn try_get_tlv(&self, kind: u8) -> Option<&ProxyTlv> {
+        self.tlvs.iter().find(|tlv: &&ProxyTlv| tlv.kind == kind)
+    }
+
+    /// Decodes a PROXY protocol v1 or v2 header from the start of a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<(Self, usize)>, ServerError>` - The header and its length, `None` if more
+    ///   bytes are needed, or an error if the bytes are not a valid header.
+    pub fn decode(data: &[u8]) -> Result<Option<(Self, usize)>, ServerError> {
+        match Self::decode_state(data)? {
+            ProxyDecodeState::Complete(header, length) => Ok(Some((header, length))),
+            ProxyDecodeState::Incomplete(_) => Ok(None),
+        }
+    }
+
+    /// Decodes a header and reports how many more bytes are needed when it is incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyDecodeState, ServerError>` - The decoding progress, or an error.
+    pub(crate) fn decode_state(data: &[u8]) -> Result<ProxyDecodeState, ServerError> {
+        let v1_prefix: usize = data.len().min(PROXY_V1_SIGNATURE.len());
+        if data[..v1_prefix] == PROXY_V1_SIGNATURE[..v1_prefix] {
+            if data.len() < PROXY_V1_SIGNATURE.len() {
+                return Ok(ProxyDecodeState::Incomplete(
+                    PROXY_V1_SIGNATURE.len() - data.len(),
+                ));
+            }
+            return Self::decode_v1(data);
+        }
+        let v2_prefix: usize = data.len().min(PROXY_V2_SIGNATURE.len());
+        if data[..v2_prefix] == PROXY_V2_SIGNATURE[..v2_prefix] {
+            if data.len() < PROXY_V2_HEADER_LENGTH {
+                return Ok(ProxyDecodeState::Incomplete(
+                    PROXY_V2_HEADER_LENGTH - data.len(),
+                ));
+            }
+            return Self::decode_v2(data);
+        }
+        Err(ServerError::ProxyProtocol(
+            "missing PROXY protocol signature".to_owned(),
+        ))
+    }
+
+    /// Decodes a text header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received bytes, starting with the v1 signature.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyDecodeState, ServerError>` - The decoding progress, or an error.
+    fn decode_v1(data: &[u8]) -> Result<ProxyDecodeState, ServerError> {
+        let scan: &[u8] = &data[..data.len().min(PROXY_V1_MAX_LENGTH)];
+        let Some(end) = scan.windows(2).position(|window: &[u8]| window == b"\r\n") else {
+            if data.len() >= PROXY_V1_MAX_LENGTH {
+                return Err(ServerError::ProxyProtocol(
+                    "v1 header exceeds 107 bytes".to_owned(),
+                ));
+            }
+            return Ok(ProxyDecodeState::Incomplete(1));
+        };
+        let invalid = || ServerError::ProxyProtocol("malformed v1 header".to_owned());
+        let line: &str = std::str::from_utf8(&data[..end]).map_err(|_| invalid())?;
+        let parts: Vec<&str> = line.split(' ').collect();
+        if parts[0] != "PROXY" {
+            return Err(invalid());
+        }
+        let header: ProxyHeader = match parts.get(1).copied() {
+            Some("UNKNOWN") => ProxyHeader {
+                version: ProxyVersion::V1,
+                command: ProxyCommand::Local,
+                source: None,
+                destination: None,
+                tlvs: Vec::new(),
+            },
+            Some(family @ ("TCP4" | "TCP6")) if parts.len() == 6 => {
+                let source_ip: IpAddr = parts[2].parse().map_err(|_| invalid())?;
+                let destination_ip: IpAddr = parts[3].parse().map_err(|_| invalid())?;
+                let source_port: u16 = parts[4].parse().map_err(|_| invalid())?;
+                let destination_port: u16 = parts[5].parse().map_err(|_| invalid())?;
+                if source_ip.is_ipv4() != (family == "TCP4")
+                    || destination_ip.is_ipv4() != (family == "TCP4")
+                {
+                    return Err(invalid());
+                }
+                ProxyHeader {
+                    version: ProxyVersion::V1,
+                    command: ProxyCommand::Proxy,
+                    source: Some(SocketAddr::new(source_ip, source_port)),
+                    destination: Some(SocketAddr::new(destination_ip, destination_port)),
+                    tlvs: Vec::new(),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(ProxyDecodeState::Complete(header, end + 2))
+    }
+
+    /// Decodes a binary header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received bytes, holding at least the fixed v2 header.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ProxyDecodeState, ServerError>` - The decoding progress, or an error.
+    fn decode_v2(data: &[u8]) -> Result<ProxyDecodeState, ServerError> {
+        let invalid = |reason: &str| {
+            ServerError::ProxyProtocol(format!("malformed v2 header{COLON_SPACE}{reason}"))
+        };
+        let version_command: u8 = data[12];
+        if version_command >> 4 != 2 {
+            return Err(invalid("unsupported version"));
+        }
+        let command: ProxyCommand = match version_command & 0x0F {
+            0 => ProxyCommand::Local,
+            1 => ProxyCommand::Proxy,
+            _ => return Err(invalid("unsupported command")),
+        };
+        let family: u8 = data[13];
+        let length: usize = u16::from_be_bytes([data[14], data[15]]) as usize;
+        let total: usize = PROXY_V2_HEADER_LENGTH + length;
+        if data.len() < total {
+            return Ok(ProxyDecodeState::Incomplete(total - data.len()));
+        }
+        let payload: &[u8] = &data[PROXY_V2_HEADER_LENGTH..total];
+        let (source, destination, address_length): (OptionSocketAddr, OptionSocketAddr, usize) =
+            match family >> 4 {
+                0x1 if payload.len() >= 12 => {
+                    let source_ip: [u8; 4] = [payload[0], payload[1], payload[2], payload[3]];
+                    let destination_ip: [u8; 4] = [payload[4], payload[5], payload[6], payload[7]];
+                    (
+                        Some(SocketAddr::new(
+                            IpAddr::from(source_ip),
+                            u16::from_be_bytes([payload[8], payload[9]]),
+                        )),
+                        Some(SocketAddr::new(
+                            IpAddr::from(destination_ip),
+                            u16::from_be_bytes([payload[10], payload[11]]),
+                        )),
+                        12,
+                    )
+                }
+                0x2 if payload.len() >= 36 => {
+                    let mut source_ip: [u8; 16] = [0; 16];
+                    let mut destination_ip: [u8; 16] = [0; 16];
+                    source_ip.copy_from_slice(&payload[..16]);
+                    destination_ip.copy_from_slice(&payload[16..32]);
+                    (
+                        Some(SocketAddr::new(
+                            IpAddr::from(source_ip),
+                            u16::from_be_bytes([payload[32], payload[33]]),
+                        )),
+                        Some(SocketAddr::new(
+                            IpAddr::from(destination_ip),
+                            u16::from_be_bytes([payload[34], payload[35]]),
+                        )),
+                        36,
+                    )
+                }
+                0x3 if payload.len() >= 216 => (None, None, 216),
+                0x0 => (None, None, 0),
+                _ => return Err(invalid("address block too short")),
+            };
+        let mut tlvs: Vec<ProxyTlv> = Vec::new();
+        let mut rest: &[u8] = &payload[address_length..];
+        while !rest.is_empty() {
+            if rest.len() < 3 {
+                return Err(invalid("truncated TLV"));
+            }
+            let value_length: usize = u16::from_be_bytes([rest[1], rest[2]]) as usize;
+            if rest.len() < 3 + value_length {
+                return Err(invalid("truncated TLV"));
+            }
+            tlvs.push(ProxyTlv {
+                kind: rest[0],
+                value: rest[3..3 + value_length].to_vec(),
+            });
+            rest = &rest[3 + value_length..];
+        }
+        let header: ProxyHeader = ProxyHeader {
+            version: ProxyVersion::V2,
+            command,
+            source: if command == ProxyCommand::Proxy {
+                source
+            } else {
+                None
+            },
+            destination: if command == ProxyCommand::Proxy {
+                destination
+            } else {
+                None
+            },
+            tlvs,
+        };
+        Ok(ProxyDecodeState::Complete(header, total))
+    }
+
+    /// Reads a header into the connection buffer and consumes it.
+    ///
+    /// The bytes received after the header stay buffered for the first request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut ConnectionReader` - The reader of the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ServerError>` - The decoded header, or an error on failure.
+    pub(crate) async fn read_from(reader: &mut ConnectionReader) -> Result<Self, ServerError> {
+        loop {
+            match Self::decode_state(&reader.buffer)? {
+                ProxyDecodeState::Complete(header, length) => {
+                    reader.buffer.advance(length);
+                    return Ok(header);
+                }
+                ProxyDecodeState::Incomplete(needed) => {
+                    let len: usize = reader.buffer.len() + needed;
+                    reader.fill_to(len).await?;
+                }
+            }
+        }
+    }
+}
+
+impl IpCidr {
+    /// Creates a network from an address and prefix length.
+    ///
+    /// An IPv4-mapped IPv6 network with a prefix of at least 96 bits is stored as the
+    /// equivalent IPv4 network.
+    ///
+    /// # Arguments
+    ///
+    /// - `IpAddr` - The network address.
+    /// - `u8` - The prefix length.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Self>` - The network, or `None` if the prefix is too long for the address family.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
+        let max: u8 = if addr.is_ipv4() { 32 } else { 128 };
+        if prefix_len > max {
+            return None;
+        }
+        match addr.to_canonical() {
+            IpAddr::V4(network) if addr.is_ipv6() && prefix_len >= 96 => Some(Self {
+                addr: IpAddr::V4(network),
+                prefix_len: prefix_len - 96,
+            }),
+            _ => Some(Self { addr, prefix_len }),
+        }
+    }
+
+    /// Gets the network address.
+    ///
+    /// # Returns
+    ///
+    /// - `IpAddr` - The network address.
+    pub fn get_addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Gets the prefix length.
+    ///
+    /// # Returns
+    ///
+    /// - `u8` - The prefix length.
+    pub fn get_prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Checks whether an address belongs to the network.
+    ///
+    /// IPv4 addresses and their IPv4-mapped IPv6 form are treated as the same address.
+    ///
+    /// # Arguments
+    ///
+    /// - `IpAddr` - The address to check.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the address is inside the network.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask: u32 = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V4(_), IpAddr::V6(_)) => false,
+            (IpAddr::V6(network), ip) => {
+                let ip: Ipv6Addr = match ip {
+                    IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+                    IpAddr::V6(ip) => ip,
+                };
+                let mask: u128 = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+        }
+    }
+}
+
+/// Implementation of `FromStr` for `IpCidr`.
+impl FromStr for IpCidr {
+    type Err = String;
+
+    /// Parses `addr/prefix`, or a bare address as a single-host network.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The text to parse.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, String>` - The network, or a description of the problem.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len): (&str, Option<&str>) = match value.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (value, None),
+        };
+        let addr: IpAddr = addr
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid network address{COLON_SPACE}{value}"))?;
+        let prefix_len: u8 = match prefix_len {
+            Some(prefix_len) => prefix_len
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid prefix length{COLON_SPACE}{value}"))?,
+            None if addr.is_ipv4() => 32,
+            None => 128,
+        };
+        Self::new(addr, prefix_len)
+            .ok_or_else(|| format!("prefix length out of range{COLON_SPACE}{value}"))
+    }
+}
+
+/// Implementation of `Display` for `IpCidr`.
+impl Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// Serializes an `IpCidr` in its `addr/prefix` text form.
+#[cfg(feature = "serde")]
+impl serde::Serialize for IpCidr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes an `IpCidr` from its `addr/prefix` text form.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IpCidr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value: String = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
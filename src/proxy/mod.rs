@@ -0,0 +1,8 @@
+mod r#const;
+mod r#enum;
+mod r#impl;
+mod r#struct;
+
+pub use {r#const::*, r#enum::*, r#struct::*};
+
+use super::*;
@@ -0,0 +1,34 @@
+use super::*;
+
+/// A type-length-value record carried by a PROXY protocol v2 header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyTlv {
+    /// The record type, such as `0x01` for ALPN or `0x02` for the authority.
+    pub(crate) kind: u8,
+    /// The raw record value.
+    pub(crate) value: Vec<u8>,
+}
+
+/// A decoded PROXY protocol header describing the original connection.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyHeader {
+    /// The protocol version the header was encoded with.
+    pub(crate) version: ProxyVersion,
+    /// Whether the connection was relayed or opened by the proxy itself.
+    pub(crate) command: ProxyCommand,
+    /// The original client address, if the header carried one.
+    pub(crate) source: OptionSocketAddr,
+    /// The original destination address, if the header carried one.
+    pub(crate) destination: OptionSocketAddr,
+    /// The TLV records of a v2 header.
+    pub(crate) tlvs: Vec<ProxyTlv>,
+}
+
+/// An IP network in CIDR notation, such as `10.0.0.0/8`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IpCidr {
+    /// The network address.
+    pub(crate) addr: IpAddr,
+    /// The number of leading bits that identify the network.
+    pub(crate) prefix_len: u8,
+}
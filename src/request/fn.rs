@@ -0,0 +1,18 @@
+/// Finds the first occurrence of a byte sequence.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The bytes to search.
+/// - `&[u8]` - The sequence to find; an empty sequence matches at the start.
+///
+/// # Returns
+///
+/// - `Option<usize>` - The offset of the first match, if any.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window: &[u8]| window == needle)
+}
@@ -0,0 +1,345 @@
+use super::*;
+
+impl ConnectionReader {
+    /// Creates a reader for a connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream` - The stream to read from.
+    /// - `PooledBuffer` - The buffer borrowed for the connection.
+    /// - `&ServerConfigData` - The buffer size, frame limit, framing and body mode to read with.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ConnectionReader instance.
+    pub(crate) fn new(
+        stream: ArcRwLockStream,
+        buffer: PooledBuffer,
+        config: &ServerConfigData,
+    ) -> Self {
+        Self {
+            stream,
+            buffer,
+            buffer_size: config.buffer_size,
+            max_frame_size: config.max_frame_size,
+            framing: config.framing.clone(),
+            stream_request_body: config.stream_request_body,
+            eof: false,
+            short_read: false,
+        }
+    }
+
+    /// Reads up to `buffer_size` more bytes from the socket into the buffer.
+    ///
+    /// The stream lock is not held while waiting for data, so sends, flushes and
+    /// shutdowns on the same connection are not held up.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, ServerError>` - The number of bytes read, zero once the peer has closed.
+    pub(crate) async fn fill(&mut self) -> Result<usize, ServerError> {
+        if self.eof {
+            return Ok(0);
+        }
+        self.buffer.reserve(self.buffer_size);
+        let read: usize = loop {
+            self.stream.readable().await.map_err(ServerError::read)?;
+            let stream: ArcRwLockReadGuard<'_, TcpStream> = self.stream.read().await;
+            match stream.try_read_buf(&mut (&mut *self.buffer).limit(self.buffer_size)) {
+                Ok(read) => break read,
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {}
+                Err(error) => return Err(ServerError::read(error)),
+            }
+        };
+        if read == 0 {
+            self.eof = true;
+        }
+        self.short_read = read < self.buffer_size;
+        Ok(read)
+    }
+
+    /// Reads until at least `len` bytes are buffered.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes needed.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ServerError>` - Ok(()) once buffered, or `ConnectionClosed` if the peer closes first.
+    pub(crate) async fn fill_to(&mut self, len: usize) -> Result<(), ServerError> {
+        while self.buffer.len() < len {
+            if self.fill().await? == 0 {
+                return Err(ServerError::ConnectionClosed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the request handed to the hooks.
+    ///
+    /// When request bodies are streamed only the head is read, otherwise a frame.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The request data, empty if the peer closed without sending any.
+    pub(crate) async fn read_request(&mut self) -> Result<Request, ServerError> {
+        if self.stream_request_body {
+            return self.read_head().await;
+        }
+        Ok(self.read_frame().await?.unwrap_or_default())
+    }
+
+    /// Reads the next message with the configured framing.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, ServerError>` - The message, `None` if the peer closed
+    ///   between messages, or `ConnectionClosed` if it closed in the middle of one.
+    pub(crate) async fn read_frame(&mut self) -> Result<Option<Request>, ServerError> {
+        if self.buffer.is_empty() && self.fill().await? == 0 {
+            return Ok(None);
+        }
+        match self.framing.clone() {
+            Framing::Auto => self.read_message(true).await.map(Some),
+            Framing::Terminated => self.read_message(false).await.map(Some),
+            Framing::Delimiter(delimiter) => self.read_until(&delimiter).await.map(Some),
+            Framing::LengthPrefixed => {
+                self.fill_to(size_of::<u32>()).await?;
+                let len: usize = u32::from_be_bytes([
+                    self.buffer[0],
+                    self.buffer[1],
+                    self.buffer[2],
+                    self.buffer[3],
+                ]) as usize;
+                if len > self.max_frame_size {
+                    return Err(ServerError::FrameTooLarge(self.max_frame_size));
+                }
+                self.fill_to(size_of::<u32>() + len).await?;
+                self.buffer.advance(size_of::<u32>());
+                Ok(Some(self.buffer.split_to(len).freeze()))
+            }
+            Framing::Raw => Ok(Some(self.buffer.split().freeze())),
+        }
+    }
+
+    /// Reads a message that ends at the first `SPLIT_REQUEST_BYTES`, or without one at the
+    /// end of the connection.
+    ///
+    /// Bytes after the terminator stay buffered for the next message. When short reads
+    /// end messages, an unterminated message also ends once a read returns fewer than
+    /// `buffer_size` bytes, as the peer has sent everything it had.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether a short read ends an unterminated message.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The message without its terminator, or
+    ///   `FrameTooLarge` if no terminator arrives within `max_frame_size`.
+    pub(crate) async fn read_message(
+        &mut self,
+        short_read_ends: bool,
+    ) -> Result<Request, ServerError> {
+        let mut searched: usize = 0;
+        loop {
+            if let Some(position) = find_bytes(&self.buffer[searched..], SPLIT_REQUEST_BYTES) {
+                let message: Request = self.buffer.split_to(searched + position).freeze();
+                self.buffer.advance(SPLIT_REQUEST_BYTES.len());
+                return Ok(message);
+            }
+            if self.buffer.len() > self.max_frame_size {
+                return Err(ServerError::FrameTooLarge(self.max_frame_size));
+            }
+            if short_read_ends && self.short_read && !self.buffer.is_empty() {
+                break;
+            }
+            searched = self
+                .buffer
+                .len()
+                .saturating_sub(SPLIT_REQUEST_BYTES.len() - 1);
+            if self.fill().await? == 0 {
+                break;
+            }
+        }
+        Ok(self.buffer.split().freeze())
+    }
+
+    /// Reads up to the first `SPLIT_REQUEST_BYTES`, leaving the bytes after it for the body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The head without its terminator, or everything
+    ///   received when the connection closes first.
+    pub(crate) async fn read_head(&mut self) -> Result<Request, ServerError> {
+        match self.read_until(SPLIT_REQUEST_BYTES).await {
+            Err(ServerError::ConnectionClosed) => Ok(self.buffer.split().freeze()),
+            result => result,
+        }
+    }
+
+    /// Reads exactly `len` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes to read.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The bytes, `FrameTooLarge` above the frame limit,
+    ///   or `ConnectionClosed` if the peer closes first.
+    pub(crate) async fn read_exact(&mut self, len: usize) -> Result<Request, ServerError> {
+        if len > self.max_frame_size {
+            return Err(ServerError::FrameTooLarge(self.max_frame_size));
+        }
+        self.fill_to(len).await?;
+        Ok(self.buffer.split_to(len).freeze())
+    }
+
+    /// Reads up to the next occurrence of a delimiter, which is consumed but not returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The delimiter.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The bytes before the delimiter, `FrameTooLarge` above
+    ///   the frame limit, or `ConnectionClosed` if the peer closes first.
+    pub(crate) async fn read_until(&mut self, delimiter: &[u8]) -> Result<Request, ServerError> {
+        let mut searched: usize = 0;
+        loop {
+            if let Some(position) = find_bytes(&self.buffer[searched..], delimiter) {
+                let frame: Request = self.buffer.split_to(searched + position).freeze();
+                self.buffer.advance(delimiter.len());
+                return Ok(frame);
+            }
+            if self.buffer.len() > self.max_frame_size {
+                return Err(ServerError::FrameTooLarge(self.max_frame_size));
+            }
+            searched = self
+                .buffer
+                .len()
+                .saturating_sub(delimiter.len().saturating_sub(1));
+            if self.fill().await? == 0 {
+                return Err(ServerError::ConnectionClosed);
+            }
+        }
+    }
+
+    /// Reads the next chunk of the connection, starting with the buffered bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, ServerError>` - The chunk, or `None` once the peer has closed.
+    pub(crate) async fn next_chunk(&mut self) -> Result<Option<Request>, ServerError> {
+        if self.buffer.is_empty() && self.fill().await? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.buffer.split().freeze()))
+    }
+}
+
+impl RequestBody {
+    /// Creates a body reading from a connection reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<Mutex<ConnectionReader>>` - The reader of the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new RequestBody instance.
+    pub(crate) fn new(reader: Arc<Mutex<ConnectionReader>>) -> Self {
+        Self {
+            reader,
+            remainder: Request::new(),
+            pending: None,
+        }
+    }
+
+    /// Reads the next chunk of the body as it arrives.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, ServerError>` - The chunk, or `None` once the peer has closed.
+    pub async fn next_chunk(&mut self) -> Result<Option<Request>, ServerError> {
+        if !self.remainder.is_empty() {
+            return Ok(Some(std::mem::take(&mut self.remainder)));
+        }
+        if let Some(pending) = self.pending.take() {
+            return pending.await;
+        }
+        self.reader.lock().await.next_chunk().await
+    }
+
+    /// Reads the rest of the body into memory.
+    ///
+    /// This buffers everything the peer sends until it closes the connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ServerError>` - The remaining bytes of the body.
+    pub async fn read_all(&mut self) -> Result<Request, ServerError> {
+        let mut body: BytesMut = BytesMut::new();
+        while let Some(chunk) = self.next_chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body.freeze())
+    }
+}
+
+/// Implementation of `Stream` for `RequestBody`.
+impl Stream for RequestBody {
+    type Item = std::io::Result<Request>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this: &mut Self = &mut self;
+        if !this.remainder.is_empty() {
+            return Poll::Ready(Some(Ok(std::mem::take(&mut this.remainder))));
+        }
+        let reader: &Arc<Mutex<ConnectionReader>> = &this.reader;
+        let pending: &mut BoxRequestChunkFuture = this.pending.get_or_insert_with(|| {
+            let reader: Arc<Mutex<ConnectionReader>> = Arc::clone(reader);
+            Box::pin(async move { reader.lock().await.next_chunk().await })
+        });
+        let result: Result<Option<Request>, ServerError> =
+            std::task::ready!(pending.as_mut().poll(cx));
+        this.pending = None;
+        Poll::Ready(result.map_err(std::io::Error::from).transpose())
+    }
+}
+
+/// Implementation of `AsyncRead` for `RequestBody`.
+impl AsyncRead for RequestBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        if self.remainder.is_empty() {
+            match std::task::ready!(self.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => self.remainder = chunk,
+                Some(Err(error)) => return Poll::Ready(Err(error)),
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+        let size: usize = buf.remaining().min(self.remainder.len());
+        buf.put_slice(&self.remainder[..size]);
+        self.remainder.advance(size);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Implementation of `Debug` for `RequestBody`.
+impl fmt::Debug for RequestBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestBody")
+            .field("remainder", &self.remainder)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,3 +1,10 @@
+mod r#fn;
+mod r#impl;
+mod r#struct;
 mod r#type;
 
-pub use r#type::*;
+pub use {r#struct::*, r#type::*};
+
+pub(crate) use r#fn::*;
+
+use super::*;
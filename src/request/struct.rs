@@ -0,0 +1,37 @@
+use super::*;
+
+/// Reads the messages of one connection.
+///
+/// Bytes read past the current message stay in the buffer for the next read.
+/// The buffer is borrowed from the server pool and returns to it with the reader.
+pub(crate) struct ConnectionReader {
+    /// The stream being read.
+    pub(crate) stream: ArcRwLockStream,
+    /// The bytes read but not yet handed out.
+    pub(crate) buffer: PooledBuffer,
+    /// The maximum number of bytes read from the socket at once.
+    pub(crate) buffer_size: usize,
+    /// The maximum size of a single message.
+    pub(crate) max_frame_size: usize,
+    /// How the messages are delimited.
+    pub(crate) framing: Framing,
+    /// Whether only the head of the first message is read before the hooks run.
+    pub(crate) stream_request_body: bool,
+    /// Whether the peer has closed its side of the connection.
+    pub(crate) eof: bool,
+    /// Whether the last read returned fewer than `buffer_size` bytes.
+    pub(crate) short_read: bool,
+}
+
+/// The bytes of a connection that follow the request head, read as they arrive.
+///
+/// It can be consumed chunk by chunk, as a `Stream` of chunks or as an `AsyncRead`.
+/// Bodies of the same connection share one reader, so each byte is handed out once.
+pub struct RequestBody {
+    /// The reader of the connection.
+    pub(crate) reader: Arc<Mutex<ConnectionReader>>,
+    /// The bytes of the current chunk not yet consumed.
+    pub(crate) remainder: Request,
+    /// The chunk read started by a poll and not yet completed.
+    pub(crate) pending: Option<BoxRequestChunkFuture>,
+}
@@ -2,5 +2,10 @@
 //!
 //! This module contains type aliases for request data.
 
-/// A type alias for request data (byte vector).
-pub type Request = Vec<u8>;
+use super::*;
+
+/// A type alias for request data.
+///
+/// Requests are split off the connection read buffer without copying,
+/// and clones share the same allocation.
+pub type Request = Bytes;
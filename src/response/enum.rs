@@ -0,0 +1,27 @@
+use super::*;
+
+/// The payload carried by a `Response`.
+#[derive(Clone, Debug)]
+pub enum ResponseBody {
+    /// A single contiguous buffer.
+    Data(ResponseData),
+    /// Several buffers sent back to back with one vectored write.
+    Chunks(Vec<ResponseData>),
+    /// A byte range of a file, sent without loading it into memory.
+    File(ResponseFile),
+    /// Chunks pulled from a stream or reader while they are written.
+    Stream(ResponseStream),
+}
+
+/// How the chunks of a streamed response are delimited on the wire.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChunkFraming {
+    /// The chunks are written as they are, and the peer reads until the connection closes.
+    #[default]
+    Raw,
+    /// Each chunk is preceded by its length as a big-endian `u32`, and a zero length ends the body.
+    LengthPrefixed,
+    /// Each chunk is preceded by its hexadecimal length and CRLF and followed by CRLF,
+    /// and `0\r\n\r\n` ends the body, as in HTTP/1.1 chunked encoding.
+    Chunked,
+}
@@ -0,0 +1,24 @@
+/// Reads up to `size` bytes of a file starting at `position`, without moving its cursor.
+///
+/// # Arguments
+///
+/// - `&std::fs::File` - The file to read.
+/// - `u64` - The offset to read from.
+/// - `usize` - The maximum number of bytes to read.
+///
+/// # Returns
+///
+/// - `std::io::Result<Vec<u8>>` - The bytes read, empty at the end of the file.
+pub(crate) fn read_file_at(
+    file: &std::fs::File,
+    position: u64,
+    size: usize,
+) -> std::io::Result<Vec<u8>> {
+    let mut chunk: Vec<u8> = vec![0; size];
+    #[cfg(unix)]
+    let read: usize = std::os::unix::fs::FileExt::read_at(file, &mut chunk, position)?;
+    #[cfg(windows)]
+    let read: usize = std::os::windows::fs::FileExt::seek_read(file, &mut chunk, position)?;
+    chunk.truncate(read);
+    Ok(chunk)
+}
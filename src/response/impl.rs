@@ -1,5 +1,438 @@
 use super::*;
 
+/// Provides a default implementation for ResponseBody.
+impl Default for ResponseBody {
+    /// Creates an empty single-buffer body.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ResponseBody instance.
+    fn default() -> Self {
+        Self::Data(ResponseData::new())
+    }
+}
+
+impl ResponseBody {
+    /// Gets the in-memory buffers of the body in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `&[ResponseData]` - The buffers, empty for a file or streamed body.
+    pub fn get_chunks(&self) -> &[ResponseData] {
+        match self {
+            Self::Data(data) => std::slice::from_ref(data),
+            Self::Chunks(chunks) => chunks,
+            Self::File(_) | Self::Stream(_) => &[],
+        }
+    }
+}
+
+impl ResponseFile {
+    /// Opens a file and selects the byte range to send.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<Path>` - The path of the file.
+    /// - `RangeBounds<u64>` - The byte range, clamped to the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Self>` - The file range, or an error if the file cannot be opened or the range starts past its end.
+    pub fn open<P, R>(path: P, range: R) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+        R: RangeBounds<u64>,
+    {
+        Self::from_file(std::fs::File::open(path)?, range)
+    }
+
+    /// Selects the byte range of an open file to send.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::fs::File` - The open file.
+    /// - `RangeBounds<u64>` - The byte range, clamped to the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<Self>` - The file range, or an error if the range starts past the end of the file.
+    pub fn from_file<R>(file: std::fs::File, range: R) -> std::io::Result<Self>
+    where
+        R: RangeBounds<u64>,
+    {
+        let size: u64 = file.metadata()?.len();
+        let start: u64 = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end: u64 = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => size,
+        }
+        .min(size);
+        if start > end {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("range starts at {start} but the file ends at {end}"),
+            ));
+        }
+        Ok(Self {
+            file: Arc::new(file),
+            offset: start,
+            len: end - start,
+        })
+    }
+
+    /// Gets the offset of the first byte to send.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The offset in bytes.
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Gets the number of bytes to send.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The length in bytes.
+    pub fn get_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Reads the byte range into memory.
+    ///
+    /// This blocks the current thread while the file is read.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<ResponseData>` - The bytes of the range.
+    pub fn read(&self) -> std::io::Result<ResponseData> {
+        let mut data: Vec<u8> = Vec::with_capacity(self.len as usize);
+        let mut position: u64 = self.offset;
+        while (data.len() as u64) < self.len {
+            let size: usize =
+                (self.len - data.len() as u64).min(FILE_COPY_CHUNK_SIZE as u64) as usize;
+            let chunk: Vec<u8> = read_file_at(&self.file, position, size)?;
+            if chunk.is_empty() {
+                return Err(std::io::ErrorKind::UnexpectedEof.into());
+            }
+            position += chunk.len() as u64;
+            data.extend_from_slice(&chunk);
+        }
+        Ok(data.into())
+    }
+}
+
+impl ResponseStream {
+    /// Creates a streamed body from a source of boxed chunks.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxChunkStream` - The source of the chunks.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ResponseStream instance.
+    pub(crate) fn new(source: BoxChunkStream) -> Self {
+        Self {
+            source: Arc::new(Mutex::new(Some(source))),
+            framing: ChunkFraming::default(),
+        }
+    }
+
+    /// Creates a streamed body from a stream of chunks.
+    ///
+    /// # Arguments
+    ///
+    /// - `S: Stream<Item = T>` - The stream yielding the chunks in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ResponseStream instance.
+    pub fn from_stream<S, T>(stream: S) -> Self
+    where
+        S: Stream<Item = T> + Send + 'static,
+        T: Into<ResponseData>,
+    {
+        Self::new(Box::pin(StreamChunks(Box::pin(stream))))
+    }
+
+    /// Creates a streamed body that drains a reader.
+    ///
+    /// The reader is read in chunks of up to `DEFAULT_STREAM_CHUNK_SIZE` bytes, and a
+    /// read error ends the transfer with `ResponseError::StreamError`.
+    ///
+    /// # Arguments
+    ///
+    /// - `R: AsyncRead` - The reader to drain.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ResponseStream instance.
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        Self::new(Box::pin(ReaderChunks {
+            reader: Box::pin(reader),
+            buffer: BytesMut::new(),
+            chunk_size: DEFAULT_STREAM_CHUNK_SIZE,
+            done: false,
+        }))
+    }
+
+    /// Sets how the chunks are delimited on the wire.
+    ///
+    /// # Arguments
+    ///
+    /// - `ChunkFraming` - The framing to apply.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated stream.
+    pub fn framing(mut self, framing: ChunkFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    /// Gets how the chunks are delimited on the wire.
+    ///
+    /// # Returns
+    ///
+    /// - `ChunkFraming` - The framing.
+    pub fn get_framing(&self) -> ChunkFraming {
+        self.framing
+    }
+}
+
+/// Implementation of `Debug` for `ResponseStream`.
+impl fmt::Debug for ResponseStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseStream")
+            .field("framing", &self.framing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TransferReport {
+    /// Gets the number of payload bytes written, excluding framing.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of bytes.
+    pub fn get_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Gets the number of chunks written.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of chunks.
+    pub fn get_chunks(&self) -> u64 {
+        self.chunks
+    }
+
+    /// Gets the error that stopped the transfer.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&ResponseError>` - The error, or `None` when the whole body was sent.
+    pub fn try_get_error(&self) -> Option<&ResponseError> {
+        self.error.as_ref()
+    }
+
+    /// Checks whether the whole body was sent.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the transfer finished without error.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Converts the report into a result.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of payload bytes, or the error that stopped the transfer.
+    pub fn into_result(self) -> TransferResult {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.bytes),
+        }
+    }
+}
+
+impl ResponseOrder {
+    /// Creates the reorder buffer of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream` - The stream the responses are written to.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A reorder buffer expecting the response of request zero first.
+    pub(crate) fn new(stream: ArcRwLockStream) -> Self {
+        Self {
+            stream,
+            state: Mutex::new(ResponseOrderState::default()),
+            turn: Notify::new(),
+        }
+    }
+}
+
+impl ResponseSlot {
+    /// Sends buffers in order, holding them if an earlier response is still being written.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = D>` - The buffers in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) once written or held, or an error on failure.
+    pub(crate) async fn try_send_all<I, D>(&self, data: I) -> ResponseResult
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        {
+            let mut state: MutexGuard<'_, ResponseOrderState> = self.order.state.lock().await;
+            if state.current != self.sequence || state.draining {
+                state.held.entry(self.sequence).or_default().chunks.extend(
+                    data.into_iter()
+                        .map(|buffer: D| ResponseData::copy_from_slice(buffer.as_ref())),
+                );
+                return Ok(());
+            }
+        }
+        self.order.stream.try_send_all(data).await
+    }
+
+    /// Waits until the response of this request is the one being written.
+    ///
+    /// The turn lasts until `finish` is called, so the caller can then write to the
+    /// stream directly.
+    pub(crate) async fn wait_turn(&self) {
+        loop {
+            let state: MutexGuard<'_, ResponseOrderState> = self.order.state.lock().await;
+            if state.current == self.sequence && !state.draining {
+                return;
+            }
+            let mut turn: Pin<&mut Notified<'_>> = pin!(self.order.turn.notified());
+            turn.as_mut().enable();
+            drop(state);
+            turn.await;
+        }
+    }
+
+    /// Marks the response of this request as complete.
+    ///
+    /// When it was the one being written, the held responses that follow are written
+    /// until one is found whose request is still running. The held data is taken out
+    /// of the order state and written with the lock released; meanwhile the state is
+    /// marked as draining, so every request holds its data and a later `finish` leaves
+    /// the writing to this one.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or the first error writing held responses.
+    pub(crate) async fn finish(&self) -> ResponseResult {
+        let mut state: MutexGuard<'_, ResponseOrderState> = self.order.state.lock().await;
+        state.held.entry(self.sequence).or_default().finished = true;
+        if state.draining {
+            return Ok(());
+        }
+        state.draining = true;
+        let mut result: ResponseResult = Ok(());
+        loop {
+            let current: u64 = state.current;
+            let Some(held) = state.held.get_mut(&current) else {
+                break;
+            };
+            let chunks: Vec<ResponseData> = std::mem::take(&mut held.chunks);
+            if chunks.is_empty() {
+                if !held.finished {
+                    break;
+                }
+                state.held.remove(&current);
+                state.current += 1;
+                continue;
+            }
+            drop(state);
+            if result.is_ok() {
+                result = self.order.stream.try_send_all(&chunks).await;
+            }
+            state = self.order.state.lock().await;
+        }
+        state.draining = false;
+        drop(state);
+        self.order.turn.notify_waiters();
+        result
+    }
+}
+
+/// Implementation of `Stream` for `ReaderChunks`.
+impl Stream for ReaderChunks {
+    type Item = std::io::Result<ResponseData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this: &mut Self = &mut self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+        this.buffer.reserve(this.chunk_size);
+        let mut read_buf: ReadBuf<'_> =
+            ReadBuf::uninit(&mut this.buffer.spare_capacity_mut()[..this.chunk_size]);
+        match this.reader.as_mut().poll_read(cx, &mut read_buf) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(error)) => {
+                this.done = true;
+                Poll::Ready(Some(Err(error)))
+            }
+            Poll::Ready(Ok(())) => {
+                let read: usize = read_buf.filled().len();
+                if read == 0 {
+                    this.done = true;
+                    return Poll::Ready(None);
+                }
+                // SAFETY: the buffer is empty, as every chunk is split off it, and the reader
+                // initialized the first `read` bytes of its spare capacity through `read_buf`.
+                unsafe { this.buffer.set_len(read) };
+                Poll::Ready(Some(Ok(this.buffer.split().freeze())))
+            }
+        }
+    }
+}
+
+/// Implementation of `Stream` for `StreamChunks`.
+impl<S, T> Stream for StreamChunks<S>
+where
+    S: Stream<Item = T>,
+    T: Into<ResponseData>,
+{
+    type Item = std::io::Result<ResponseData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.0
+            .as_mut()
+            .poll_next(cx)
+            .map(|item: Option<T>| item.map(|chunk: T| Ok(chunk.into())))
+    }
+}
+
 /// Implementation of methods for the Response structure.
 impl Response {
     /// Creates a new Response from data that can be converted into ResponseData.
@@ -15,25 +448,157 @@ impl Response {
     where
         T: Into<ResponseData>,
     {
-        Self(data.into())
+        Self(ResponseBody::Data(data.into()))
     }
 
-    /// Gets a reference to the response data.
+    /// Creates a new Response from a list of chunks, sent without joining them.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = T>` - The chunks in write order.
     ///
     /// # Returns
     ///
-    /// - `&ResponseData` - Reference to the response data.
-    pub fn get_data(&self) -> &ResponseData {
-        &self.0
+    /// - `Self` - A new Response instance.
+    pub fn from_chunks<I, T>(chunks: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ResponseData>,
+    {
+        Self(ResponseBody::Chunks(
+            chunks.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Creates a new Response that sends a file range without loading it into memory.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseFile` - The file range to send.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Response instance.
+    pub fn from_file(file: ResponseFile) -> Self {
+        Self(ResponseBody::File(file))
+    }
+
+    /// Creates a new Response whose chunks are pulled from a stream while it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStream` - The streamed body.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Response instance.
+    pub fn from_stream(stream: ResponseStream) -> Self {
+        Self(ResponseBody::Stream(stream))
+    }
+
+    /// Gets the response data.
+    ///
+    /// A single buffer is shared without copying and chunks are joined into a new buffer.
+    /// A file or streamed body is not held in memory and yields empty data; read a file
+    /// range with `try_get_file_data`.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseData` - The response data.
+    pub fn get_data(&self) -> ResponseData {
+        match &self.0 {
+            ResponseBody::Data(data) => data.clone(),
+            ResponseBody::Chunks(chunks) => chunks.concat().into(),
+            ResponseBody::File(_) | ResponseBody::Stream(_) => ResponseData::new(),
+        }
     }
 
     /// Gets the response data as a mutable reference.
     ///
+    /// Chunks are joined into a single buffer first. A file or streamed body is not held
+    /// in memory, so it is replaced by an empty buffer.
+    ///
     /// # Returns
     ///
     /// - `&mut ResponseData` - Mutable reference to the response data.
     pub fn get_mut_data(&mut self) -> &mut ResponseData {
-        &mut self.0
+        if !matches!(self.0, ResponseBody::Data(_)) {
+            self.0 = ResponseBody::Data(self.get_data());
+        }
+        match &mut self.0 {
+            ResponseBody::Data(data) => data,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Gets the file range of a file-backed response.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&ResponseFile>` - The file range, or `None` for any other body.
+    pub fn get_file(&self) -> Option<&ResponseFile> {
+        match &self.0 {
+            ResponseBody::File(file) => Some(file),
+            _ => None,
+        }
+    }
+
+    /// Reads the file range of a file-backed response on the blocking thread pool.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<std::io::Result<ResponseData>>` - The bytes of the range or the error that
+    ///   prevented reading them, or `None` for any other body.
+    pub async fn try_get_file_data(&self) -> Option<std::io::Result<ResponseData>> {
+        let file: ResponseFile = self.get_file()?.clone();
+        Some(
+            tokio::task::spawn_blocking(move || file.read())
+                .await
+                .unwrap_or_else(|error: JoinError| Err(std::io::Error::other(error))),
+        )
+    }
+
+    /// Gets the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `&ResponseBody` - Reference to the response body.
+    pub fn get_body(&self) -> &ResponseBody {
+        &self.0
+    }
+
+    /// Gets the in-memory buffers of the response in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `&[ResponseData]` - The buffers, empty for a file or streamed body.
+    pub fn get_chunks(&self) -> &[ResponseData] {
+        self.0.get_chunks()
+    }
+
+    /// Appends a chunk, turning a single-buffer response into a chunked one.
+    ///
+    /// A file or streamed body cannot be extended in memory, so it is replaced by the chunk.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The chunk to append.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - Mutable reference to self for method chaining.
+    pub fn push_chunk<T>(&mut self, chunk: T) -> &mut Self
+    where
+        T: Into<ResponseData>,
+    {
+        let chunk: ResponseData = chunk.into();
+        match &mut self.0 {
+            ResponseBody::Chunks(chunks) => chunks.push(chunk),
+            ResponseBody::Data(data) if data.is_empty() => *data = chunk,
+            ResponseBody::Data(data) => self.0 = ResponseBody::Chunks(vec![data.clone(), chunk]),
+            ResponseBody::File(_) | ResponseBody::Stream(_) => self.0 = ResponseBody::Data(chunk),
+        }
+        self
     }
 
     /// Sets the response data.
@@ -49,7 +614,7 @@ impl Response {
     where
         T: Into<ResponseData>,
     {
-        self.0 = data.into();
+        self.0 = ResponseBody::Data(data.into());
         self
     }
 
@@ -59,7 +624,7 @@ impl Response {
     ///
     /// - `&mut Self` - Mutable reference to self for method chaining.
     pub fn clear(&mut self) -> &mut Self {
-        self.0.clear();
+        self.0 = ResponseBody::default();
         self
     }
 
@@ -67,22 +632,28 @@ impl Response {
     ///
     /// # Returns
     ///
-    /// - `bool` - True if the response data is empty, false otherwise.
+    /// - `bool` - True if the response data is empty, false otherwise; always false for a streamed body.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        !matches!(self.0, ResponseBody::Stream(_)) && self.len() == 0
     }
 
     /// Gets the length of the response data.
     ///
     /// # Returns
     ///
-    /// - `usize` - The length of the response data in bytes.
+    /// - `usize` - The length of the response data in bytes, zero for a streamed body of unknown length.
     pub fn len(&self) -> usize {
-        self.0.len()
+        match &self.0 {
+            ResponseBody::File(file) => file.len as usize,
+            body => body.get_chunks().iter().map(ResponseData::len).sum(),
+        }
     }
 
     /// Attempts to send the response through the provided stream.
     ///
+    /// Chunks are written with vectored writes under a single lock, file ranges
+    /// are sent without being loaded into memory, and streamed bodies are sent once.
+    ///
     /// # Arguments
     ///
     /// - `&ArcRwLockStream` - The stream to send the response through.
@@ -91,7 +662,12 @@ impl Response {
     ///
     /// - `ResponseResult` - Ok(()) on success, or an error on failure.
     pub async fn try_send(&self, stream: &ArcRwLockStream) -> ResponseResult {
-        stream.try_send(&self.0).await
+        match &self.0 {
+            ResponseBody::Data(data) => stream.try_send(data).await,
+            ResponseBody::Chunks(chunks) => stream.try_send_all(chunks).await,
+            ResponseBody::File(file) => stream.try_send_file(file).await.map(|_: u64| ()),
+            ResponseBody::Stream(body) => stream.try_send_stream(body).await.map(|_: u64| ()),
+        }
     }
 
     /// Sends the response through the provided stream.
@@ -172,7 +748,39 @@ impl From<ResponseData> for Response {
     ///
     /// - `Self` - A new Response instance.
     fn from(data: ResponseData) -> Self {
-        Self(data)
+        Self(ResponseBody::Data(data))
+    }
+}
+
+/// Implementation of `From<ResponseFile>` for `Response`.
+impl From<ResponseFile> for Response {
+    /// Converts a file range into a Response.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseFile` - The file range to send.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Response instance.
+    fn from(file: ResponseFile) -> Self {
+        Self(ResponseBody::File(file))
+    }
+}
+
+/// Implementation of `From<Vec<u8>>` for `Response`.
+impl From<Vec<u8>> for Response {
+    /// Converts a byte vector into a Response without copying.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The byte vector to convert.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Response instance.
+    fn from(data: Vec<u8>) -> Self {
+        Self(ResponseBody::Data(data.into()))
     }
 }
 
@@ -188,7 +796,7 @@ impl From<&[u8]> for Response {
     ///
     /// - `Self` - A new Response instance.
     fn from(data: &[u8]) -> Self {
-        Self(data.to_owned())
+        Self(ResponseBody::Data(Bytes::copy_from_slice(data)))
     }
 }
 
@@ -204,7 +812,7 @@ impl From<String> for Response {
     ///
     /// - `Self` - A new Response instance.
     fn from(data: String) -> Self {
-        Self(data.into_bytes())
+        Self(ResponseBody::Data(data.into()))
     }
 }
 
@@ -220,6 +828,6 @@ impl From<&str> for Response {
     ///
     /// - `Self` - A new Response instance.
     fn from(data: &str) -> Self {
-        Self(data.as_bytes().to_owned())
+        Self(ResponseBody::Data(Bytes::copy_from_slice(data.as_bytes())))
     }
 }
@@ -1,7 +1,11 @@
+mod r#enum;
+mod r#fn;
 mod r#impl;
 mod r#struct;
 mod r#type;
 
-pub use r#struct::*;
+pub use {r#enum::*, r#struct::*};
+
+pub(crate) use r#fn::*;
 
 use super::*;
@@ -3,6 +3,101 @@ use super::*;
 /// Represents an HTTP-like response structure.
 ///
 /// This structure wraps response data and provides methods for
-/// building and sending responses.
+/// building and sending responses. The data is either one buffer
+/// or a list of chunks written together.
 #[derive(Clone, Debug, Default)]
-pub struct Response(pub(super) ResponseData);
+pub struct Response(pub(super) ResponseBody);
+
+/// A byte range of an open file used as a response body.
+///
+/// On Linux the range is sent with `sendfile`; elsewhere it is copied in
+/// `FILE_COPY_CHUNK_SIZE` chunks. Clones share the file handle.
+#[derive(Clone, Debug)]
+pub struct ResponseFile {
+    /// The open file.
+    pub(crate) file: Arc<std::fs::File>,
+    /// The offset of the first byte to send.
+    pub(crate) offset: u64,
+    /// The number of bytes to send.
+    pub(crate) len: u64,
+}
+
+/// A response body produced while it is being sent.
+///
+/// Each chunk is written before the next one is pulled, so a slow peer slows
+/// the source down. The source can be sent once; clones share it.
+#[derive(Clone)]
+pub struct ResponseStream {
+    /// The source of the chunks, taken by the first send.
+    pub(crate) source: Arc<Mutex<Option<BoxChunkStream>>>,
+    /// How the chunks are delimited on the wire.
+    pub(crate) framing: ChunkFraming,
+}
+
+/// The outcome of sending a streamed response.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransferReport {
+    /// The number of payload bytes written, excluding framing.
+    pub(crate) bytes: u64,
+    /// The number of chunks written.
+    pub(crate) chunks: u64,
+    /// The error that stopped the transfer, if any.
+    pub(crate) error: Option<ResponseError>,
+}
+
+/// Adapts an `AsyncRead` into a stream of chunks.
+pub(crate) struct ReaderChunks {
+    /// The reader being drained.
+    pub(crate) reader: Pin<Box<dyn AsyncRead + Send>>,
+    /// The buffer the next chunk is read into.
+    pub(crate) buffer: BytesMut,
+    /// The maximum size of a chunk.
+    pub(crate) chunk_size: usize,
+    /// Whether the reader reached its end or failed.
+    pub(crate) done: bool,
+}
+
+/// Adapts a stream of infallible chunks into a stream of response chunks.
+pub(crate) struct StreamChunks<S>(pub(crate) Pin<Box<S>>);
+
+/// The reorder buffer of a pipelined connection.
+///
+/// The response of the oldest unfinished request is written straight to the
+/// stream; responses of later requests are held until their turn comes.
+pub(crate) struct ResponseOrder {
+    /// The stream of the connection.
+    pub(crate) stream: ArcRwLockStream,
+    /// The position of the writer and the held responses.
+    pub(crate) state: Mutex<ResponseOrderState>,
+    /// Wakes the requests waiting for their turn when the writer moves on.
+    pub(crate) turn: Notify,
+}
+
+/// The mutable state of a `ResponseOrder`.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseOrderState {
+    /// The sequence number of the request whose response is being written.
+    pub(crate) current: u64,
+    /// The responses of requests that are not yet being written, by sequence number.
+    pub(crate) held: BTreeMap<u64, HeldResponse>,
+    /// Whether `finish` is writing held responses, so the current request holds its data too.
+    pub(crate) draining: bool,
+}
+
+/// The response data of a request held by a `ResponseOrder`.
+#[derive(Debug, Default)]
+pub(crate) struct HeldResponse {
+    /// The data sent so far, in send order.
+    pub(crate) chunks: Vec<ResponseData>,
+    /// Whether the hooks of the request have finished.
+    pub(crate) finished: bool,
+}
+
+/// The place of a pipelined request in the response order of its connection.
+#[derive(Clone)]
+pub(crate) struct ResponseSlot {
+    /// The position of the request on the connection, starting at zero.
+    pub(crate) sequence: u64,
+    /// The reorder buffer of the connection.
+    pub(crate) order: Arc<ResponseOrder>,
+}
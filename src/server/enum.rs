@@ -0,0 +1,10 @@
+/// Describes why a running server stopped accepting connections.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShutdownReason {
+    /// The server was stopped through `ServerControlHook::shutdown`.
+    Requested,
+    /// The server stopped accepting and waited for its connections through `ServerControlHook::drain`.
+    Drained,
+    /// The listeners were handed over to another process and the connections were drained.
+    HandedOver,
+}
@@ -8,6 +8,10 @@ impl Default for ServerData {
             hook: vec![],
             task_panic: vec![],
             read_error: vec![],
+            state: Arc::new(HashMap::new()),
+            listener: vec![],
+            #[cfg(unix)]
+            systemd_notifier: None,
         }
     }
 }
@@ -16,13 +20,200 @@ impl Default for ServerData {
 impl Default for ServerControlHook {
     fn default() -> Self {
         Self {
-            wait_hook: Arc::new(|| Box::pin(async {})),
-            shutdown_hook: Arc::new(|| Box::pin(async {})),
+            wait_hook: Arc::new(|| Box::pin(async { Ok(ShutdownReason::Requested) })),
+            shutdown_hook: Arc::new(|_| Box::pin(async {})),
+            metrics: Arc::new(ServerMetrics::default()),
+            server: Server::default(),
+            rebind_sender: unbounded_channel().0,
+            data_sender: channel(Arc::new(ServerData::default())).0,
         }
     }
 }
 
+/// Provides a default implementation for ListenerGroup.
+impl Default for ListenerGroup {
+    fn default() -> Self {
+        Self {
+            tasks: JoinSet::new(),
+            task_generation: HashMap::new(),
+            generation: 0,
+            generation_sender: channel(0).0,
+        }
+    }
+}
+
+impl ListenerGroup {
+    /// Checks whether any accept task of the current generation is still running.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the current listeners are still accepting.
+    pub(crate) fn has_current_tasks(&self) -> bool {
+        self.task_generation
+            .values()
+            .any(|generation: &u64| *generation == self.generation)
+    }
+}
+
+/// Provides a default implementation for ServerMetrics.
+impl Default for ServerMetrics {
+    fn default() -> Self {
+        Self {
+            running: AtomicBool::new(false),
+            accepted_connections: AtomicU64::new(0),
+            active_connections: AtomicU64::new(0),
+            read_errors: AtomicU64::new(0),
+            task_panics: AtomicU64::new(0),
+            idle: Notify::new(),
+            listeners: std::sync::Mutex::new(Vec::new()),
+            started_at: Instant::now(),
+            buffer_pool: BufferPool::default(),
+        }
+    }
+}
+
+impl ServerMetrics {
+    /// Takes a snapshot of the current counter values.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerStats` - The snapshot of the counters.
+    pub(crate) fn snapshot(&self) -> ServerStats {
+        ServerStats {
+            accepted_connections: self.accepted_connections.load(Ordering::Relaxed),
+            active_connections: self.active_connections.load(Ordering::Relaxed),
+            read_errors: self.read_errors.load(Ordering::Relaxed),
+            task_panics: self.task_panics.load(Ordering::Relaxed),
+            uptime: self.started_at.elapsed(),
+            buffer_pool: self.buffer_pool.stats(),
+        }
+    }
+
+    /// Records the end of a connection and wakes drain waiters when none are left.
+    pub(crate) fn finish_connection(&self) {
+        if self.active_connections.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.idle.notify_waiters();
+        }
+    }
+
+    /// Waits until no connection is being processed.
+    pub(crate) async fn wait_idle(&self) {
+        loop {
+            let notified = self.idle.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.active_connections.load(Ordering::Acquire) == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Duplicates the handles of the listeners currently accepting.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<StdTcpListener>, ServerError>` - The duplicated listeners, or an error on failure.
+    pub(crate) fn clone_listeners(&self) -> Result<Vec<StdTcpListener>, ServerError> {
+        let listeners = self
+            .listeners
+            .lock()
+            .map_err(|error| ServerError::Unknown(error.to_string()))?;
+        listeners
+            .iter()
+            .map(|listener: &StdTcpListener| {
+                listener
+                    .try_clone()
+                    .map_err(|error: std::io::Error| ServerError::Handover(error.into()))
+            })
+            .collect()
+    }
+
+    /// Replaces the handles of the listeners currently accepting.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<StdTcpListener>` - The new listener handles.
+    pub(crate) fn set_listeners(&self, listeners: Vec<StdTcpListener>) {
+        if let Ok(mut guard) = self.listeners.lock() {
+            *guard = listeners;
+        }
+    }
+}
+
+impl ServerStats {
+    /// Gets the total number of accepted connections.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of accepted connections.
+    pub fn get_accepted_connections(&self) -> u64 {
+        self.accepted_connections
+    }
+
+    /// Gets the number of connections currently being processed.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of active connections.
+    pub fn get_active_connections(&self) -> u64 {
+        self.active_connections
+    }
+
+    /// Gets the total number of connections closed by a read error.
+    ///
+    /// The read may be of the PROXY header, the first request or a later pipelined or
+    /// multiplexed frame.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of read errors.
+    pub fn get_read_errors(&self) -> u64 {
+        self.read_errors
+    }
+
+    /// Gets the total number of hook tasks that panicked.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of task panics.
+    pub fn get_task_panics(&self) -> u64 {
+        self.task_panics
+    }
+
+    /// Gets the time elapsed since the server started.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The server uptime.
+    pub fn get_uptime(&self) -> Duration {
+        self.uptime
+    }
+
+    /// Gets the counters of the read buffer pool.
+    ///
+    /// # Returns
+    ///
+    /// - `BufferPoolStats` - The pool hits, misses and idle buffers.
+    pub fn get_buffer_pool(&self) -> BufferPoolStats {
+        self.buffer_pool
+    }
+}
+
 impl ServerData {
+    /// Starts a server built by `ServerBuilder::build` and begins accepting connections.
+    ///
+    /// Connections are handled with this snapshot as is; `ServerControlHook::reload`
+    /// publishes a new snapshot rather than changing this one.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerControlHook, ServerError>` - The control hook on success, or an error on failure.
+    pub async fn run(self: &Arc<Self>) -> Result<ServerControlHook, ServerError> {
+        let server: Server = Server(Arc::new(RwLock::new(Self::clone(self))));
+        server.run_snapshot(Arc::clone(self)).await
+    }
+
     /// Gets a reference to the configuration.
     ///
     /// # Returns
@@ -94,6 +285,65 @@ impl ServerData {
     pub(crate) fn get_mut_read_error(&mut self) -> &mut ServerHookList {
         &mut self.read_error
     }
+
+    /// Gets the application state shared with every connection context.
+    ///
+    /// # Returns
+    ///
+    /// - `&Arc<TypeMapArcAnySendSync>` - The shared state, keyed by type.
+    pub(crate) fn get_state(&self) -> &Arc<TypeMapArcAnySendSync> {
+        &self.state
+    }
+
+    /// Registers an application state value, replacing any previous value of the same type.
+    ///
+    /// # Arguments
+    ///
+    /// - `T: Any + Send + Sync` - The state value.
+    pub(crate) fn insert_state<T>(&mut self, value: T)
+    where
+        T: Any + Send + Sync,
+    {
+        Arc::make_mut(&mut self.state).insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Gets a reference to the pre-opened listener list.
+    ///
+    /// # Returns
+    ///
+    /// - `&InheritedListenerList` - Reference to the pre-opened listener list.
+    pub fn get_listener(&self) -> &InheritedListenerList {
+        &self.listener
+    }
+
+    /// Gets a mutable reference to the pre-opened listener list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut InheritedListenerList` - Mutable reference to the pre-opened listener list.
+    pub(crate) fn get_mut_listener(&mut self) -> &mut InheritedListenerList {
+        &mut self.listener
+    }
+
+    /// Gets the configured service manager notifier.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&SystemdNotifier>` - The notifier if one was set.
+    #[cfg(unix)]
+    pub fn get_systemd_notifier(&self) -> Option<&SystemdNotifier> {
+        self.systemd_notifier.as_ref()
+    }
+
+    /// Gets a mutable reference to the service manager notifier.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Option<SystemdNotifier>` - Mutable reference to the notifier.
+    #[cfg(unix)]
+    pub(crate) fn get_mut_systemd_notifier(&mut self) -> &mut Option<SystemdNotifier> {
+        &mut self.systemd_notifier
+    }
 }
 
 /// Provides a default implementation for Server.
@@ -114,6 +364,16 @@ impl Server {
         Self::default()
     }
 
+    /// Starts collecting the server state synchronously.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerBuilder` - A builder holding the default configuration and no handlers.
+    #[inline(always)]
+    pub const fn builder() -> ServerBuilder {
+        ServerBuilder::new()
+    }
+
     /// Acquires a read lock on the inner server data.
     ///
     /// # Returns
@@ -224,19 +484,338 @@ impl Server {
         self
     }
 
-    /// Creates a TCP listener bound to the configured address。
+    /// Registers application state reachable from every hook through `Context::state`.
+    ///
+    /// One value is kept per type; registering a second value of the same type replaces
+    /// the first for connections accepted afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `T: Any + Send + Sync` - The state value, such as a connection pool or cache.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn state<T>(&self, value: T) -> &Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.write().await.insert_state(value);
+        self
+    }
+
+    /// Adds a pre-opened listener the server accepts on instead of binding the configured address.
+    ///
+    /// # Arguments
+    ///
+    /// - `StdTcpListener` - The listener to accept connections on.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn listener(&self, listener: StdTcpListener) -> &Self {
+        self.write()
+            .await
+            .get_mut_listener()
+            .push(Arc::new(listener));
+        self
+    }
+
+    /// Adopts the listeners inherited from the parent process through `LISTEN_FDS`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, ServerError>` - The number of adopted listeners, or an error on failure.
+    #[cfg(unix)]
+    pub async fn inherit_listen_fds(&self) -> Result<usize, ServerError> {
+        let listeners: Vec<StdTcpListener> = take_listen_fds()
+            .map_err(|error: std::io::Error| ServerError::Handover(error.into()))?;
+        let count: usize = listeners.len();
+        for listener in listeners {
+            self.listener(listener).await;
+        }
+        Ok(count)
+    }
+
+    /// Sets the notifier used to report readiness, shutdown and watchdog pings to the service manager.
+    ///
+    /// Without one, the server notifies through `NOTIFY_SOCKET` when it is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `SystemdNotifier` - The notifier to use.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    #[cfg(unix)]
+    pub async fn systemd_notifier(&self, notifier: SystemdNotifier) -> &Self {
+        *self.write().await.get_mut_systemd_notifier() = Some(notifier);
+        self
+    }
+
+    /// Adopts the socket-activated listeners matching the configured `listen_fd_names`.
     ///
     /// # Returns
     ///
-    /// - `Result<TcpListener, ServerError>` - The listener on success, or an error on failure.
-    async fn create_tcp_listener(&self) -> Result<TcpListener, ServerError> {
+    /// - `Result<InheritedListenerList, ServerError>` - The adopted listeners, or an error on failure.
+    #[cfg(unix)]
+    async fn adopt_activated_listeners(&self) -> Result<InheritedListenerList, ServerError> {
+        let names: Vec<String> = self.read().await.get_config().listen_fd_names.clone();
+        let activated: Vec<(String, StdTcpListener)> = take_named_listen_fds()
+            .map_err(|error: std::io::Error| ServerError::Handover(error.into()))?;
+        for (name, listener) in activated {
+            if names.is_empty() || names.contains(&name) {
+                self.listener(listener).await;
+            }
+        }
+        Ok(self.read().await.get_listener().clone())
+    }
+
+    /// Adopts the listeners sent by another process with `ServerControlHook::handover`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&StdUnixStream` - The connected Unix socket to receive the listeners from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, ServerError>` - The number of adopted listeners, or an error on failure.
+    #[cfg(unix)]
+    pub async fn receive_listeners(&self, socket: &StdUnixStream) -> Result<usize, ServerError> {
+        let listeners: Vec<StdTcpListener> = receive_listeners(socket)
+            .map_err(|error: std::io::Error| ServerError::Handover(error.into()))?;
+        let count: usize = listeners.len();
+        for listener in listeners {
+            self.listener(listener).await;
+        }
+        Ok(count)
+    }
+
+    /// Creates the listeners the server accepts on.
+    ///
+    /// Pre-opened listeners are used when present, then socket-activated listeners from
+    /// `LISTEN_FDS`, otherwise a new listener is bound to the configured address.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<StdTcpListener>, ServerError>` - The listeners on success, or an error on failure.
+    async fn create_tcp_listeners(&self) -> Result<Vec<StdTcpListener>, ServerError> {
+        #[allow(unused_mut)]
+        let mut inherited: InheritedListenerList = self.read().await.get_listener().clone();
+        #[cfg(unix)]
+        if inherited.is_empty() {
+            inherited = self.adopt_activated_listeners().await?;
+        }
+        if !inherited.is_empty() {
+            return inherited
+                .iter()
+                .map(|listener: &Arc<StdTcpListener>| {
+                    listener
+                        .try_clone()
+                        .map_err(|error: std::io::Error| ServerError::TcpBind(error.into()))
+                })
+                .collect();
+        }
         let config: ServerConfigData = self.read().await.get_config().clone();
-        let host: String = config.host;
-        let port: u16 = config.port;
-        let addr: String = Self::get_bind_addr(&host, port);
+        Self::bind_tcp_listener(&config)
+            .await
+            .map(|listener: StdTcpListener| vec![listener])
+    }
+
+    /// Binds a new listener to the address in the given configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ServerConfigData` - The configuration holding the host and port.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<StdTcpListener, ServerError>` - The non-blocking listener, or an error on failure.
+    async fn bind_tcp_listener(config: &ServerConfigData) -> Result<StdTcpListener, ServerError> {
+        let addr: String = Self::get_bind_addr(&config.host, config.port);
         TcpListener::bind(&addr)
             .await
-            .map_err(|error: std::io::Error| ServerError::TcpBind(error.to_string()))
+            .and_then(|listener: TcpListener| listener.into_std())
+            .map_err(|error: std::io::Error| ServerError::TcpBind(error.into()))
+    }
+
+    /// Starts one accept task per listener and returns duplicated handles for handover.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<StdTcpListener>` - The listeners to accept on.
+    /// - `&mut ListenerGroup` - The accept tasks of the running server.
+    /// - `&Arc<ServerMetrics>` - The counters of the running server.
+    /// - `&Receiver<Option<ShutdownReason>>` - The shutdown signal.
+    /// - `&Receiver<Arc<ServerData>>` - The server state new connections are handled with.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<StdTcpListener>, ServerError>` - The duplicated handles, or an error on failure.
+    fn spawn_accept_tasks(
+        listeners: Vec<StdTcpListener>,
+        group: &mut ListenerGroup,
+        metrics: &Arc<ServerMetrics>,
+        shutdown_receiver: &Receiver<Option<ShutdownReason>>,
+        data_receiver: &Receiver<Arc<ServerData>>,
+    ) -> Result<Vec<StdTcpListener>, ServerError> {
+        let mut handles: Vec<StdTcpListener> = Vec::with_capacity(listeners.len());
+        let mut tcp_listeners: Vec<TcpListener> = Vec::with_capacity(listeners.len());
+        for listener in listeners {
+            handles.push(
+                listener
+                    .try_clone()
+                    .map_err(|error: std::io::Error| ServerError::TcpBind(error.into()))?,
+            );
+            tcp_listeners.push(
+                TcpListener::from_std(listener)
+                    .map_err(|error: std::io::Error| ServerError::TcpBind(error.into()))?,
+            );
+        }
+        let generation: u64 = group.generation;
+        for tcp_listener in tcp_listeners {
+            let id: tokio::task::Id = group
+                .tasks
+                .spawn(Self::accept_connections(
+                    tcp_listener,
+                    Arc::clone(metrics),
+                    shutdown_receiver.clone(),
+                    data_receiver.clone(),
+                    group.generation_sender.subscribe(),
+                    generation,
+                ))
+                .id();
+            group.task_generation.insert(id, generation);
+        }
+        Ok(handles)
+    }
+
+    /// Supervises the accept tasks until the current listeners stop.
+    ///
+    /// Listener swaps received from `ServerControlHook::reload` retire the previous
+    /// accept tasks without touching the connections they accepted.
+    ///
+    /// # Arguments
+    ///
+    /// - `ListenerGroup` - The accept tasks of the running server.
+    /// - `UnboundedReceiver<Vec<StdTcpListener>>` - The replacement listeners.
+    /// - `Arc<ServerMetrics>` - The counters of the running server.
+    /// - `Receiver<Option<ShutdownReason>>` - The shutdown signal.
+    /// - `Receiver<Arc<ServerData>>` - The server state new connections are handled with.
+    ///
+    /// # Returns
+    ///
+    /// - `ShutdownResult` - The shutdown reason, or the error that stopped a listener.
+    async fn supervise_listeners(
+        mut group: ListenerGroup,
+        mut rebind_receiver: UnboundedReceiver<Vec<StdTcpListener>>,
+        metrics: Arc<ServerMetrics>,
+        shutdown_receiver: Receiver<Option<ShutdownReason>>,
+        data_receiver: Receiver<Arc<ServerData>>,
+    ) -> ShutdownResult {
+        let mut result: ShutdownResult = Ok(ShutdownReason::Requested);
+        while group.has_current_tasks() {
+            tokio::select! {
+                joined = group.tasks.join_next_with_id() => {
+                    let (id, outcome): (tokio::task::Id, ShutdownResult) = match joined {
+                        Some(Ok((id, outcome))) => (id, outcome),
+                        Some(Err(error)) if error.is_cancelled() => {
+                            group.task_generation.remove(&error.id());
+                            continue;
+                        }
+                        Some(Err(error)) => {
+                            group.task_generation.remove(&error.id());
+                            (error.id(), Err(ServerError::Unknown(error.to_string())))
+                        }
+                        None => break,
+                    };
+                    let generation: Option<u64> = group.task_generation.remove(&id);
+                    if generation != Some(group.generation) {
+                        continue;
+                    }
+                    match outcome {
+                        Ok(reason) => result = result.map(|_| reason),
+                        Err(error) => {
+                            result = Err(error);
+                            group.tasks.abort_all();
+                        }
+                    }
+                }
+                Some(listeners) = rebind_receiver.recv() => {
+                    group.generation += 1;
+                    let _: Result<(), tokio::sync::watch::error::SendError<u64>> =
+                        group.generation_sender.send(group.generation);
+                    match Self::spawn_accept_tasks(
+                        listeners,
+                        &mut group,
+                        &metrics,
+                        &shutdown_receiver,
+                        &data_receiver,
+                    ) {
+                        Ok(handles) => metrics.set_listeners(handles),
+                        Err(error) => {
+                            result = Err(error);
+                            group.tasks.abort_all();
+                        }
+                    }
+                }
+            }
+        }
+        group.tasks.abort_all();
+        while group.tasks.join_next().await.is_some() {}
+        result
+    }
+
+    /// Accepts connections on a single listener until shutdown is requested or the listener is retired.
+    ///
+    /// # Arguments
+    ///
+    /// - `TcpListener` - The listener to accept on.
+    /// - `Arc<ServerMetrics>` - The counters of the running server.
+    /// - `Receiver<Option<ShutdownReason>>` - The shutdown signal.
+    /// - `Receiver<Arc<ServerData>>` - The server state new connections are handled with.
+    /// - `Receiver<u64>` - The current listener generation.
+    /// - `u64` - The generation this listener belongs to.
+    ///
+    /// # Returns
+    ///
+    /// - `ShutdownResult` - The shutdown reason, or the error that stopped the listener.
+    async fn accept_connections(
+        tcp_listener: TcpListener,
+        metrics: Arc<ServerMetrics>,
+        mut shutdown_receiver: Receiver<Option<ShutdownReason>>,
+        mut data_receiver: Receiver<Arc<ServerData>>,
+        mut generation_receiver: Receiver<u64>,
+        generation: u64,
+    ) -> ShutdownResult {
+        let mut data: Arc<ServerData> = Arc::clone(&data_receiver.borrow_and_update());
+        loop {
+            tokio::select! {
+                Ok(()) = data_receiver.changed() => {
+                    data = Arc::clone(&data_receiver.borrow_and_update());
+                }
+                changed = generation_receiver.changed() => {
+                    let current: u64 = *generation_receiver.borrow_and_update();
+                    if changed.is_err() || current != generation {
+                        return Ok(ShutdownReason::Requested);
+                    }
+                }
+                result = tcp_listener.accept() => {
+                    match result {
+                        Ok((stream, _)) => {
+                            let stream: ArcRwLockStream = ArcRwLockStream::from_stream(stream);
+                            Self::spawn_connection_handler(stream, Arc::clone(&data), Arc::clone(&metrics));
+                        }
+                        Err(error) => return Err(ServerError::TcpAccept(error.into())),
+                    }
+                }
+                _ = shutdown_receiver.changed() => {
+                    let reason: Option<ShutdownReason> = *shutdown_receiver.borrow_and_update();
+                    return Ok(reason.unwrap_or(ShutdownReason::Requested));
+                }
+            }
+        }
     }
 
     /// Spawns a new task to handle an incoming connection.
@@ -244,15 +823,18 @@ impl Server {
     /// # Arguments
     ///
     /// - `ArcRwLockStream` - The stream for the incoming connection.
-    async fn spawn_connection_handler(&self, stream: ArcRwLockStream) {
-        let server: Server = self.clone();
-        let hook: ServerHookList = self.read().await.get_hook().clone();
-        let task_panic: ServerHookList = self.read().await.get_task_panic().clone();
-        let buffer_size: usize = self.read().await.get_config().buffer_size;
+    /// - `Arc<ServerData>` - The server state the connection is handled with.
+    /// - `Arc<ServerMetrics>` - The counters of the running server.
+    fn spawn_connection_handler(
+        stream: ArcRwLockStream,
+        data: Arc<ServerData>,
+        metrics: Arc<ServerMetrics>,
+    ) {
+        metrics.accepted_connections.fetch_add(1, Ordering::Relaxed);
+        metrics.active_connections.fetch_add(1, Ordering::AcqRel);
         spawn(async move {
-            server
-                .handle_connection(stream, hook, task_panic, buffer_size)
-                .await;
+            Self::handle_connection(stream, &data, &metrics).await;
+            metrics.finish_connection();
         });
     }
 
@@ -261,35 +843,273 @@ impl Server {
     /// # Arguments
     ///
     /// - `ArcRwLockStream` - The stream for the connection.
-    /// - `ServerHookList` - The list of hooks to process.
-    /// - `ServerHookList` - The list of panic handlers.
-    /// - `usize` - The buffer size for reading data.
+    /// - `&ServerData` - The server state the connection was accepted with.
+    /// - `&Arc<ServerMetrics>` - The counters of the running server.
     async fn handle_connection(
-        &self,
         stream: ArcRwLockStream,
-        hook: ServerHookList,
-        task_panic: ServerHookList,
-        buffer_size: usize,
+        data: &ServerData,
+        metrics: &Arc<ServerMetrics>,
     ) {
-        let request: Request = match self.read_stream(&stream, buffer_size).await {
+        let config: &ServerConfigData = data.get_config();
+        let buffer: PooledBuffer = metrics.buffer_pool.acquire(config.buffer_size);
+        let mut reader: ConnectionReader = ConnectionReader::new(stream.clone(), buffer, config);
+        let proxy_header: Option<ProxyHeader> =
+            match Self::read_proxy_header(&stream, &mut reader, config).await {
+                Ok(proxy_header) => proxy_header,
+                Err(e) => {
+                    metrics.read_errors.fetch_add(1, Ordering::Relaxed);
+                    Self::read_error_handle(data, stream, None, e).await;
+                    return;
+                }
+            };
+        if config.pipeline_depth > 0 || config.stream_id_size > 0 {
+            let hooks: ConnectionHooks = ConnectionHooks {
+                hook: data.get_hook().clone(),
+                task_panic: data.get_task_panic().clone(),
+                proxy_header,
+            };
+            if config.stream_id_size > 0 {
+                Self::handle_multiplex(data, stream, reader, hooks, metrics).await;
+            } else {
+                Self::handle_pipeline(data, stream, reader, hooks, metrics).await;
+            }
+            return;
+        }
+        let request: Request = match reader.read_request().await {
             Ok(data) => data,
             Err(e) => {
-                self.read_error_handle(e.to_string()).await;
+                metrics.read_errors.fetch_add(1, Ordering::Relaxed);
+                Self::read_error_handle(data, stream, proxy_header, e).await;
                 return;
             }
         };
-        let ctx: Context = self.create_context(stream, request).await;
+        let ctx: Context = Self::create_context(data, stream, request, reader);
+        if let Some(proxy_header) = proxy_header {
+            ctx.set_proxy_header(proxy_header).await;
+        }
+        Self::run_hooks(&ctx, data.get_hook(), data.get_task_panic(), metrics).await;
+    }
+
+    /// Handles the framed requests of a pipelined connection.
+    ///
+    /// Up to the pipeline depth of requests run through the hook chain at once, each with
+    /// its own context sharing the connection attributes. Responses are written in request
+    /// order through the connection's `ResponseOrder`. A read error is handed to the read
+    /// error handlers once the running requests have finished.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ServerData` - The server state the connection was accepted with.
+    /// - `ArcRwLockStream` - The stream for the connection.
+    /// - `ConnectionReader` - The reader the requests are framed from.
+    /// - `ConnectionHooks` - The hooks shared by the requests.
+    /// - `&Arc<ServerMetrics>` - The counters of the running server.
+    async fn handle_pipeline(
+        server: &ServerData,
+        stream: ArcRwLockStream,
+        mut reader: ConnectionReader,
+        hooks: ConnectionHooks,
+        metrics: &Arc<ServerMetrics>,
+    ) {
+        let hooks: Arc<ConnectionHooks> = Arc::new(hooks);
+        let order: Arc<ResponseOrder> = Arc::new(ResponseOrder::new(stream.clone()));
+        let permits: Arc<Semaphore> = Arc::new(Semaphore::new(server.get_config().pipeline_depth));
+        let connection_attributes: Attributes = Attributes::default();
+        let state: &Arc<TypeMapArcAnySendSync> = server.get_state();
+        let mut tasks: JoinSet<()> = JoinSet::new();
+        let mut read_error: Option<ServerError> = None;
+        for sequence in 0.. {
+            let permit: OwnedSemaphorePermit = match Arc::clone(&permits).acquire_owned().await {
+                Ok(permit) => permit,
+                Err(_) => break,
+            };
+            let request: Request = match reader.read_frame().await {
+                Ok(Some(request)) => request,
+                Ok(None) => break,
+                Err(e) => {
+                    read_error = Some(e);
+                    break;
+                }
+            };
+            let slot: ResponseSlot = ResponseSlot {
+                sequence,
+                order: Arc::clone(&order),
+            };
+            let mut data: ContextData = ContextData::new();
+            data.stream = ContextCell::new(Some(stream.clone()));
+            data.proxy_header = ContextCell::new(hooks.proxy_header.clone());
+            data.request = ContextCell::new(request);
+            data.response_slot = ContextCell::new(Some(slot.clone()));
+            data.connection_attributes = connection_attributes.clone();
+            data.state = Arc::clone(state);
+            let ctx: Context = Context::from(data);
+            let hooks: Arc<ConnectionHooks> = Arc::clone(&hooks);
+            let metrics: Arc<ServerMetrics> = Arc::clone(metrics);
+            tasks.spawn(async move {
+                Self::run_hooks(&ctx, &hooks.hook, &hooks.task_panic, &metrics).await;
+                let _: ResponseResult = slot.finish().await;
+                drop(permit);
+            });
+            while tasks.try_join_next().is_some() {}
+        }
+        while tasks.join_next().await.is_some() {}
+        if let Some(e) = read_error {
+            metrics.read_errors.fetch_add(1, Ordering::Relaxed);
+            Self::read_error_handle(server, stream, hooks.proxy_header.clone(), e).await;
+        }
+    }
+
+    /// Handles the frames of a multiplexed connection.
+    ///
+    /// The first frame of a stream ID opens the stream: it gets its own context, sharing
+    /// the connection attributes, and runs through the hook chain concurrently with the
+    /// other streams, up to `max_streams`. Later frames of a running stream are queued for
+    /// its context, and reading pauses while the queue is full. A frame holding only the ID
+    /// cancels the stream by aborting its context and its hook task. A read error is
+    /// handed to the read error handlers once the running streams have finished.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ServerData` - The server state the connection was accepted with.
+    /// - `ArcRwLockStream` - The stream for the connection.
+    /// - `ConnectionReader` - The reader the frames are read from.
+    /// - `ConnectionHooks` - The hooks shared by the streams.
+    /// - `&Arc<ServerMetrics>` - The counters of the running server.
+    async fn handle_multiplex(
+        server: &ServerData,
+        stream: ArcRwLockStream,
+        mut reader: ConnectionReader,
+        hooks: ConnectionHooks,
+        metrics: &Arc<ServerMetrics>,
+    ) {
+        let config: &ServerConfigData = server.get_config();
+        let hooks: Arc<ConnectionHooks> = Arc::new(hooks);
+        let connection_attributes: Attributes = Attributes::default();
+        let state: &Arc<TypeMapArcAnySendSync> = server.get_state();
+        let permits: Arc<Semaphore> = Arc::new(Semaphore::new(config.max_streams));
+        let mut streams: HashMap<u64, MultiplexEntry> = HashMap::new();
+        let mut tasks: JoinSet<(u64, u64)> = JoinSet::new();
+        let read_error: Option<ServerError> = loop {
+            let mut frame: Request = match reader.read_frame().await {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break None,
+                Err(e) => break Some(e),
+            };
+            while let Some(result) = tasks.try_join_next() {
+                let Ok((id, context_id)) = result else {
+                    continue;
+                };
+                if streams
+                    .get(&id)
+                    .is_some_and(|entry: &MultiplexEntry| entry.ctx.get_id() == context_id)
+                {
+                    streams.remove(&id);
+                }
+            }
+            let Some(id) = split_stream_id(&mut frame, config.stream_id_size) else {
+                break Some(ServerError::MissingStreamId(config.stream_id_size));
+            };
+            if frame.is_empty() {
+                if let Some(entry) = streams.remove(&id) {
+                    entry.ctx.abort().await;
+                    entry.task.abort();
+                }
+                continue;
+            }
+            let frame: Request = match streams.get(&id) {
+                Some(entry) => match entry.frames.send(frame).await {
+                    Ok(()) => continue,
+                    Err(SendError(frame)) => frame,
+                },
+                None => frame,
+            };
+            let permit: OwnedSemaphorePermit = match Arc::clone(&permits).acquire_owned().await {
+                Ok(permit) => permit,
+                Err(_) => break None,
+            };
+            let (sender, receiver): (mpsc::Sender<Request>, mpsc::Receiver<Request>) =
+                mpsc::channel(STREAM_FRAME_QUEUE_CAPACITY);
+            let multiplex: MultiplexStream = MultiplexStream {
+                id,
+                id_size: config.stream_id_size,
+                framing: config.framing.clone(),
+                chunk_size: config.buffer_size,
+                frames: Arc::new(Mutex::new(receiver)),
+            };
+            let mut data: ContextData = ContextData::new();
+            data.stream = ContextCell::new(Some(stream.clone()));
+            data.proxy_header = ContextCell::new(hooks.proxy_header.clone());
+            data.request = ContextCell::new(frame);
+            data.multiplex = ContextCell::new(Some(multiplex.clone()));
+            data.connection_attributes = connection_attributes.clone();
+            data.state = Arc::clone(state);
+            let ctx: Context = Context::from(data);
+            let entry_ctx: Context = ctx.clone();
+            let hooks: Arc<ConnectionHooks> = Arc::clone(&hooks);
+            let metrics: Arc<ServerMetrics> = Arc::clone(metrics);
+            let task: AbortHandle = tasks.spawn(async move {
+                Self::run_hooks(&ctx, &hooks.hook, &hooks.task_panic, &metrics).await;
+                multiplex.close().await;
+                drop(permit);
+                (id, ctx.get_id())
+            });
+            streams.insert(
+                id,
+                MultiplexEntry {
+                    frames: sender,
+                    ctx: entry_ctx,
+                    task,
+                },
+            );
+        };
+        streams.clear();
+        while tasks.join_next().await.is_some() {}
+        if let Some(e) = read_error {
+            metrics.read_errors.fetch_add(1, Ordering::Relaxed);
+            Self::read_error_handle(server, stream, hooks.proxy_header.clone(), e).await;
+        }
+    }
 
-        for h in hook.iter() {
+    /// Runs a context through the hook chain.
+    ///
+    /// The chain stops at the first hook that panics, after the panic handlers have run.
+    /// Dropping the returned future aborts the hook that is running.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context of the request.
+    /// - `&ServerHookList` - The list of hooks to process.
+    /// - `&ServerHookList` - The list of panic handlers.
+    /// - `&ServerMetrics` - The counters of the running server.
+    async fn run_hooks(
+        ctx: &Context,
+        hook: &ServerHookList,
+        task_panic: &ServerHookList,
+        metrics: &ServerMetrics,
+    ) {
+        for (index, h) in hook.iter().enumerate() {
             let ctx_clone: Context = ctx.clone();
             let h_clone: ServerHookHandler = Arc::clone(h);
-            let join_handle: JoinHandle<()> = spawn(async move {
+            ctx.set_hook_name(None).await;
+            let started_at: Instant = Instant::now();
+            let mut running: JoinSet<()> = JoinSet::new();
+            running.spawn(async move {
                 h_clone(ctx_clone).await;
             });
-
-            match join_handle.await {
+            let Some(result) = running.join_next().await else {
+                break;
+            };
+            match result {
                 Ok(()) => {}
                 Err(e) if e.is_panic() => {
+                    metrics.task_panics.fetch_add(1, Ordering::Relaxed);
+                    let panic: TaskPanic = TaskPanic::new(
+                        e.into_panic(),
+                        index,
+                        ctx.try_get_hook_name().await,
+                        started_at.elapsed(),
+                    );
+                    ctx.set_task_panic(panic).await;
                     for panic_handler in task_panic.iter() {
                         panic_handler(ctx.clone()).await;
                     }
@@ -300,118 +1120,219 @@ impl Server {
         }
     }
 
-    /// Reads data from the stream into a request.
+    /// Reads the PROXY protocol header when enabled and the peer is a trusted upstream.
     ///
     /// # Arguments
     ///
-    /// - `&ArcRwLockStream` - The stream to read from.
-    /// - `usize` - The buffer size for reading.
+    /// - `&ArcRwLockStream` - The stream whose peer address is checked.
+    /// - `&mut ConnectionReader` - The reader the header is read through.
+    /// - `&ServerConfigData` - The configuration holding the trusted networks.
     ///
     /// # Returns
     ///
-    /// - `Result<Request, ServerError>` - The request data on success, or an error on failure.
-    async fn read_stream(
-        &self,
+    /// - `Result<Option<ProxyHeader>, ServerError>` - The header if one was expected, or an error on failure.
+    async fn read_proxy_header(
         stream: &ArcRwLockStream,
-        buffer_size: usize,
-    ) -> Result<Request, ServerError> {
-        let mut buffer: Vec<u8> = Vec::new();
-        let mut tmp_buf: Vec<u8> = vec![0u8; buffer_size];
-        let mut stream_guard: ArcRwLockWriteGuard<'_, TcpStream> = stream.write().await;
-        loop {
-            match stream_guard.read(&mut tmp_buf).await {
-                Ok(0) => break,
-                Ok(n) => {
-                    buffer.extend_from_slice(&tmp_buf[..n]);
-                    if tmp_buf[..n].ends_with(SPLIT_REQUEST_BYTES) {
-                        let end_pos: usize = buffer.len().saturating_sub(SPLIT_REQUEST_BYTES.len());
-                        buffer.truncate(end_pos);
-                        break;
-                    }
-                    if n < tmp_buf.len() {
-                        break;
-                    }
-                }
-                Err(e) => {
-                    return Err(ServerError::TcpRead(e.to_string()));
-                }
-            }
+        reader: &mut ConnectionReader,
+        config: &ServerConfigData,
+    ) -> Result<Option<ProxyHeader>, ServerError> {
+        if !config.proxy_protocol {
+            return Ok(None);
+        }
+        let trusted: bool = match stream.try_get_peer_addr().await {
+            Some(peer) => config
+                .trusted_proxies
+                .iter()
+                .any(|cidr: &IpCidr| cidr.contains(peer.ip())),
+            None => false,
+        };
+        if !trusted {
+            return Ok(None);
         }
-        Ok(buffer)
+        ProxyHeader::read_from(reader).await.map(Some)
     }
 
     /// Creates a context for processing a request.
     ///
     /// # Arguments
     ///
+    /// - `&ServerData` - The server state holding the shared application state.
     /// - `ArcRwLockStream` - The stream for the connection.
     /// - `Request` - The request data.
+    /// - `ConnectionReader` - The reader holding the bytes received after the request.
     ///
     /// # Returns
     ///
     /// - `Context` - The created context.
-    async fn create_context(&self, stream: ArcRwLockStream, request: Request) -> Context {
+    fn create_context(
+        server: &ServerData,
+        stream: ArcRwLockStream,
+        request: Request,
+        reader: ConnectionReader,
+    ) -> Context {
         let mut data: ContextData = ContextData::new();
-        data.stream = Some(stream);
-        data.request = request;
+        data.stream = ContextCell::new(Some(stream));
+        data.request = ContextCell::new(request);
+        data.reader = ContextCell::new(Some(Arc::new(Mutex::new(reader))));
+        data.state = Arc::clone(server.get_state());
         Context::from(data)
     }
 
-    /// Handles an read error by invoking the configured error handlers.
+    /// Handles a read error by invoking the configured error handlers.
+    ///
+    /// The handlers get a context holding the connection stream, the PROXY protocol header
+    /// when one was decoded, and the typed error.
     ///
     /// # Arguments
     ///
-    /// - `String` - The error message.
-    async fn read_error_handle(&self, error: String) {
-        let error_handlers: ServerHookList = self.read().await.get_read_error().clone();
-        let ctx: Context = Context::new();
-        ctx.set_data("error", error).await;
-        for handler in error_handlers.iter() {
+    /// - `&ServerData` - The server state holding the read error handlers.
+    /// - `ArcRwLockStream` - The stream of the failed connection.
+    /// - `Option<ProxyHeader>` - The PROXY protocol header, if decoded.
+    /// - `ServerError` - The read error.
+    async fn read_error_handle(
+        server: &ServerData,
+        stream: ArcRwLockStream,
+        proxy_header: Option<ProxyHeader>,
+        error: ServerError,
+    ) {
+        let mut data: ContextData = ContextData::new();
+        data.state = Arc::clone(server.get_state());
+        data.stream = ContextCell::new(Some(stream));
+        data.proxy_header = ContextCell::new(proxy_header);
+        data.server_error = ContextCell::new(Some(error));
+        let ctx: Context = Context::from(data);
+        for handler in server.get_read_error().iter() {
             handler(ctx.clone()).await;
         }
     }
 
+    /// Sends watchdog notifications while the server is running.
+    ///
+    /// # Arguments
+    ///
+    /// - `SystemdNotifier` - The notifier to ping through.
+    /// - `Arc<ServerMetrics>` - The counters of the running server.
+    #[cfg(unix)]
+    fn spawn_watchdog(notifier: SystemdNotifier, metrics: Arc<ServerMetrics>) {
+        let Some(interval) = notifier.get_watchdog() else {
+            return;
+        };
+        spawn(async move {
+            let mut ticker: tokio::time::Interval = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if !metrics.running.load(Ordering::Relaxed) {
+                    break;
+                }
+                let _: std::io::Result<()> = notifier.ping_watchdog();
+            }
+        });
+    }
+
     /// Starts the server and begins accepting connections.
     ///
+    /// The configuration is validated first, so an invalid one fails with
+    /// `ServerError::Config` before any socket is bound.
+    ///
+    /// The server state is copied into a frozen snapshot that connections are handled
+    /// with, so accepting a connection takes no lock. Later changes to this `Server` are
+    /// not picked up until `ServerControlHook::reload` or `reload_hooks` publishes a new
+    /// snapshot.
+    ///
     /// # Returns
     ///
     /// - `Result<ServerControlHook, ServerError>` - The control hook on success, or an error on failure.
     pub async fn run(&self) -> Result<ServerControlHook, ServerError> {
-        let tcp_listener: TcpListener = self.create_tcp_listener().await?;
-        let server: Server = self.clone();
-        let (wait_sender, wait_receiver) = channel(());
-        let (shutdown_sender, mut shutdown_receiver) = channel(());
+        let snapshot: Arc<ServerData> = Arc::new(self.read().await.clone());
+        self.run_snapshot(snapshot).await
+    }
+
+    /// Starts accepting connections with the given frozen snapshot of this server's state.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<ServerData>` - The snapshot connections are handled with.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerControlHook, ServerError>` - The control hook on success, or an error on failure.
+    async fn run_snapshot(
+        &self,
+        snapshot: Arc<ServerData>,
+    ) -> Result<ServerControlHook, ServerError> {
+        snapshot.get_config().validate()?;
+        let buffer_pool: BufferPool = snapshot.get_config().buffer_pool();
+        let listeners: Vec<StdTcpListener> = self.create_tcp_listeners().await?;
+        let metrics: Arc<ServerMetrics> = Arc::new(ServerMetrics {
+            buffer_pool,
+            ..Default::default()
+        });
+        let (wait_sender, wait_receiver) = channel::<Option<ShutdownResult>>(None);
+        let (shutdown_sender, shutdown_receiver) = channel::<Option<ShutdownReason>>(None);
+        let (rebind_sender, rebind_receiver) = unbounded_channel::<Vec<StdTcpListener>>();
+        let (data_sender, data_receiver) = channel::<Arc<ServerData>>(Arc::clone(&snapshot));
+        let mut group: ListenerGroup = ListenerGroup::default();
+        let handles: Vec<StdTcpListener> = Self::spawn_accept_tasks(
+            listeners,
+            &mut group,
+            &metrics,
+            &shutdown_receiver,
+            &data_receiver,
+        )?;
+        metrics.set_listeners(handles);
+        metrics.running.store(true, Ordering::Relaxed);
+        #[cfg(unix)]
+        let notifier: Option<SystemdNotifier> = snapshot
+            .get_systemd_notifier()
+            .cloned()
+            .or_else(SystemdNotifier::from_env);
+        #[cfg(unix)]
+        if let Some(notifier) = notifier.clone() {
+            let _: std::io::Result<()> = notifier.ready();
+            Self::spawn_watchdog(notifier, Arc::clone(&metrics));
+        }
+        let metrics_clone: Arc<ServerMetrics> = Arc::clone(&metrics);
         let accept_connections: JoinHandle<()> = spawn(async move {
-            loop {
-                tokio::select! {
-                    result = tcp_listener.accept() => {
-                        match result {
-                            Ok((stream, _)) => {
-                                let stream: ArcRwLockStream = ArcRwLockStream::from_stream(stream);
-                                server.spawn_connection_handler(stream).await;
-                            }
-                            Err(_) => break,
-                        }
-                    }
-                    _ = shutdown_receiver.changed() => {
-                        break;
-                    }
-                }
+            let result: ShutdownResult = Self::supervise_listeners(
+                group,
+                rebind_receiver,
+                Arc::clone(&metrics_clone),
+                shutdown_receiver,
+                data_receiver,
+            )
+            .await;
+            metrics_clone.running.store(false, Ordering::Relaxed);
+            metrics_clone.set_listeners(Vec::new());
+            #[cfg(unix)]
+            if let Some(notifier) = notifier {
+                let _: std::io::Result<()> = notifier.stopping();
             }
-            let _: Result<(), tokio::sync::watch::error::SendError<()>> = wait_sender.send(());
+            if matches!(
+                result,
+                Ok(ShutdownReason::Drained | ShutdownReason::HandedOver)
+            ) {
+                metrics_clone.wait_idle().await;
+            }
+            let _: Result<(), tokio::sync::watch::error::SendError<Option<ShutdownResult>>> =
+                wait_sender.send(Some(result));
         });
-        let wait_hook = Arc::new(move || {
-            let mut wait_receiver_clone = wait_receiver.clone();
+        let wait_hook: ServerWaitHook = Arc::new(move || {
+            let mut wait_receiver_clone: Receiver<Option<ShutdownResult>> = wait_receiver.clone();
             Box::pin(async move {
-                let _: Result<(), tokio::sync::watch::error::RecvError> =
-                    wait_receiver_clone.changed().await;
-            }) as Pin<Box<dyn Future<Output = ()> + Send + 'static>>
+                match wait_receiver_clone
+                    .wait_for(|result: &Option<ShutdownResult>| result.is_some())
+                    .await
+                {
+                    Ok(result) => result.clone().unwrap_or(Ok(ShutdownReason::Requested)),
+                    Err(error) => Err(ServerError::Unknown(error.to_string())),
+                }
+            }) as Pin<Box<dyn Future<Output = ShutdownResult> + Send + 'static>>
         });
-        let shutdown_hook = Arc::new(move || {
-            let shutdown_sender_clone: Sender<()> = shutdown_sender.clone();
+        let shutdown_hook: ServerShutdownHook = Arc::new(move |reason: ShutdownReason| {
+            let shutdown_sender_clone: Sender<Option<ShutdownReason>> = shutdown_sender.clone();
             Box::pin(async move {
-                let _: Result<(), tokio::sync::watch::error::SendError<()>> =
-                    shutdown_sender_clone.send(());
+                let _: Result<(), tokio::sync::watch::error::SendError<Option<ShutdownReason>>> =
+                    shutdown_sender_clone.send(Some(reason));
             }) as Pin<Box<dyn Future<Output = ()> + Send + 'static>>
         });
         spawn(async move {
@@ -420,6 +1341,10 @@ impl Server {
         Ok(ServerControlHook {
             wait_hook,
             shutdown_hook,
+            metrics,
+            server: self.clone(),
+            rebind_sender,
+            data_sender,
         })
     }
 }
@@ -427,12 +1352,329 @@ impl Server {
 /// Implementation of methods for the ServerControlHook structure.
 impl ServerControlHook {
     /// Waits for the server to finish.
-    pub async fn wait(&self) {
-        (self.wait_hook)().await;
+    ///
+    /// # Returns
+    ///
+    /// - `ShutdownResult` - The reason the server stopped, or the error that stopped it.
+    pub async fn wait(&self) -> ShutdownResult {
+        (self.wait_hook)().await
     }
 
     /// Initiates a graceful shutdown of the server.
     pub async fn shutdown(&self) {
-        (self.shutdown_hook)().await;
+        (self.shutdown_hook)(ShutdownReason::Requested).await;
+    }
+
+    /// Stops accepting new connections and lets `wait` resolve once the active ones finish.
+    pub async fn drain(&self) {
+        (self.shutdown_hook)(ShutdownReason::Drained).await;
+    }
+
+    /// Checks whether the server is still accepting connections.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the accept loop is running, otherwise false.
+    pub fn is_running(&self) -> bool {
+        self.metrics.running.load(Ordering::Relaxed)
+    }
+
+    /// Takes a snapshot of the server counters.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerStats` - The current connection and error counters.
+    pub fn stats(&self) -> ServerStats {
+        self.metrics.snapshot()
+    }
+
+    /// Applies a new configuration to the running server.
+    ///
+    /// New connections use the new limits and buffer sizes immediately. When the host or
+    /// port changes and the server bound its own listener, a listener is bound to the new
+    /// address and swapped in; connections accepted on the old one keep running.
+    ///
+    /// The address of inherited listeners and the shape of the read buffer pool are fixed
+    /// while the server runs, so changing the host or port of a server running on inherited
+    /// listeners, or the buffer pool classes or capacity, is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ServerConfig` - The configuration to apply.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ServerError>` - Ok(()) once applied, or an error if the configuration is invalid, changes a fixed setting, or the new address cannot be bound.
+    pub async fn reload(&self, config: &ServerConfig) -> Result<(), ServerError> {
+        let next: ServerConfigData = config.get_data().await;
+        next.validate()?;
+        let (previous, inherited): (ServerConfigData, bool) = {
+            let data: ArcRwLockReadGuard<'_, ServerData> = self.server.read().await;
+            (data.get_config().clone(), !data.get_listener().is_empty())
+        };
+        let rebind: bool = previous.host != next.host || previous.port != next.port;
+        if inherited && rebind {
+            return Err(ConfigError::ConflictingOptions(
+                "host and port cannot change while running on inherited listeners".to_owned(),
+            )
+            .into());
+        }
+        if previous.buffer_pool_classes != next.buffer_pool_classes {
+            return Err(ConfigError::InvalidField {
+                field: "buffer_pool_classes".to_owned(),
+                message: "cannot change while the server is running".to_owned(),
+            }
+            .into());
+        }
+        if previous.buffer_pool_capacity != next.buffer_pool_capacity {
+            return Err(ConfigError::InvalidField {
+                field: "buffer_pool_capacity".to_owned(),
+                message: "cannot change while the server is running".to_owned(),
+            }
+            .into());
+        }
+        if rebind {
+            let listener: StdTcpListener = Server::bind_tcp_listener(&next).await?;
+            self.rebind_sender
+                .send(vec![listener])
+                .map_err(|_| ServerError::Unknown("server is not running".to_owned()))?;
+        }
+        let mut data: ArcRwLockWriteGuard<'_, ServerData> = self.server.write().await;
+        *data.get_mut_server_config() = next;
+        self.data_sender.send_replace(Arc::new(data.clone()));
+        Ok(())
+    }
+
+    /// Replaces the hook, task panic and read error handlers of the running server.
+    ///
+    /// New connections use the handlers registered on `source`; connections already
+    /// being processed keep the handlers they started with.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Server` - The server whose handlers are copied.
+    pub async fn reload_hooks(&self, source: &Server) {
+        let (hook, task_panic, read_error): (ServerHookList, ServerHookList, ServerHookList) = {
+            let data: ArcRwLockReadGuard<'_, ServerData> = source.read().await;
+            (
+                data.get_hook().clone(),
+                data.get_task_panic().clone(),
+                data.get_read_error().clone(),
+            )
+        };
+        let mut data: ArcRwLockWriteGuard<'_, ServerData> = self.server.write().await;
+        *data.get_mut_hook() = hook;
+        *data.get_mut_task_panic() = task_panic;
+        *data.get_mut_read_error() = read_error;
+        self.data_sender.send_replace(Arc::new(data.clone()));
+    }
+
+    /// Duplicates the listeners the server is accepting on.
+    ///
+    /// The returned handles share the underlying sockets, so they keep the ports open
+    /// after this server stops.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<StdTcpListener>, ServerError>` - The duplicated listeners, or an error on failure.
+    pub fn export_listeners(&self) -> Result<Vec<StdTcpListener>, ServerError> {
+        self.metrics.clone_listeners()
+    }
+
+    /// Sends the listeners to another process and drains this server.
+    ///
+    /// The receiving process adopts them with `Server::receive_listeners`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&StdUnixStream` - The connected Unix socket to send the listeners through.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ServerError>` - Ok(()) once the listeners are sent, or an error on failure.
+    #[cfg(unix)]
+    pub async fn handover(&self, socket: &StdUnixStream) -> Result<(), ServerError> {
+        let listeners: Vec<StdTcpListener> = self.export_listeners()?;
+        send_listeners(socket, &listeners)
+            .map_err(|error: std::io::Error| ServerError::Handover(error.into()))?;
+        (self.shutdown_hook)(ShutdownReason::HandedOver).await;
+        Ok(())
+    }
+
+    /// Spawns a successor process that inherits the listeners through `LISTEN_FDS` and drains this server.
+    ///
+    /// The successor adopts them with `Server::inherit_listen_fds`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Command` - The command that starts the successor.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Child, ServerError>` - The spawned successor, or an error on failure.
+    #[cfg(unix)]
+    pub async fn handover_to_command(&self, command: &mut Command) -> Result<Child, ServerError> {
+        export_listen_fds(command, self.export_listeners()?);
+        let child: Child = command
+            .spawn()
+            .map_err(|error: std::io::Error| ServerError::Handover(error.into()))?;
+        (self.shutdown_hook)(ShutdownReason::HandedOver).await;
+        Ok(child)
+    }
+}
+
+impl ServerBuilder {
+    /// Creates a builder holding the default configuration and no handlers.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ServerBuilder instance.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            server_config: ServerConfigData::new(),
+            hook: Vec::new(),
+            task_panic: Vec::new(),
+            read_error: Vec::new(),
+            state: Vec::new(),
+            listener: Vec::new(),
+            #[cfg(unix)]
+            systemd_notifier: None,
+        }
+    }
+
+    /// Sets the server configuration, including its buffer and frame limits.
+    ///
+    /// # Arguments
+    ///
+    /// - `C: Into<ServerConfigData>` - A `ServerConfigBuilder` or the configuration data.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn config<C>(mut self, config: C) -> Self
+    where
+        C: Into<ServerConfigData>,
+    {
+        self.server_config = config.into();
+        self
+    }
+
+    /// Adds a typed hook to the hook list.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The hook type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn hook<H>(mut self) -> Self
+    where
+        H: ServerHook,
+    {
+        self.hook.push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Adds a panic handler to the task panic handler list.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn task_panic<H>(mut self) -> Self
+    where
+        H: ServerHook,
+    {
+        self.task_panic.push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Adds an error handler to the read error handler list.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn read_error<H>(mut self) -> Self
+    where
+        H: ServerHook,
+    {
+        self.read_error.push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers application state reachable from every hook through `Context::state`.
+    ///
+    /// # Arguments
+    ///
+    /// - `T: Any + Send + Sync` - The state value.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn state<T>(mut self, value: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.state.push((TypeId::of::<T>(), Arc::new(value)));
+        self
+    }
+
+    /// Adds a pre-opened listener the server accepts on instead of binding the configured address.
+    ///
+    /// # Arguments
+    ///
+    /// - `StdTcpListener` - The listener to accept connections on.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    pub fn listener(mut self, listener: StdTcpListener) -> Self {
+        self.listener.push(Arc::new(listener));
+        self
+    }
+
+    /// Sets the notifier used to report readiness and shutdown to the service manager.
+    ///
+    /// # Arguments
+    ///
+    /// - `SystemdNotifier` - The notifier to use.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated builder.
+    #[cfg(unix)]
+    pub fn systemd_notifier(mut self, notifier: SystemdNotifier) -> Self {
+        self.systemd_notifier = Some(notifier);
+        self
+    }
+
+    /// Freezes the collected state into the immutable snapshot the server runs from.
+    ///
+    /// The snapshot is shared with every connection without locking; start it with
+    /// `ServerData::run`. Use `Server::new` instead for a server edited in place before running.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<ServerData>` - The frozen server state.
+    pub fn build(self) -> Arc<ServerData> {
+        Arc::new(ServerData {
+            server_config: self.server_config,
+            hook: self.hook,
+            task_panic: self.task_panic,
+            read_error: self.read_error,
+            state: Arc::new(self.state.into_iter().collect()),
+            listener: self.listener,
+            #[cfg(unix)]
+            systemd_notifier: self.systemd_notifier,
+        })
     }
 }
@@ -1,6 +1,8 @@
+mod r#enum;
 mod r#impl;
 mod r#struct;
+mod r#type;
 
-pub use r#struct::*;
+pub use {r#enum::*, r#struct::*, r#type::*};
 
 use super::*;
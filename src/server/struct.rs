@@ -15,6 +15,13 @@ pub struct ServerData {
     pub(crate) task_panic: ServerHookList,
     /// The error handlers for server operations.
     pub(crate) read_error: ServerHookList,
+    /// Application state shared with every connection context, keyed by type.
+    pub(crate) state: Arc<TypeMapArcAnySendSync>,
+    /// Pre-opened listeners used instead of binding the configured address.
+    pub(crate) listener: InheritedListenerList,
+    /// The service manager notifier, falling back to `NOTIFY_SOCKET` when unset.
+    #[cfg(unix)]
+    pub(crate) systemd_notifier: Option<SystemdNotifier>,
 }
 
 /// The primary server structure that provides a thread-safe interface to the server's state.
@@ -25,13 +32,108 @@ pub struct ServerData {
 #[derive(Clone)]
 pub struct Server(pub(super) ArcRwLock<ServerData>);
 
+/// Collects the configuration, handlers and listeners of a server synchronously.
+///
+/// Nothing is locked or awaited; `build` freezes the collected state into the
+/// immutable snapshot the accept path shares with every connection.
+#[derive(Clone, Default)]
+pub struct ServerBuilder {
+    /// The server configuration collected so far.
+    pub(crate) server_config: ServerConfigData,
+    /// The request hooks collected so far.
+    pub(crate) hook: ServerHookList,
+    /// The task panic handlers collected so far.
+    pub(crate) task_panic: ServerHookList,
+    /// The read error handlers collected so far.
+    pub(crate) read_error: ServerHookList,
+    /// The application state collected so far, in registration order.
+    pub(crate) state: Vec<(TypeId, Arc<dyn Any + Send + Sync>)>,
+    /// The pre-opened listeners collected so far.
+    pub(crate) listener: InheritedListenerList,
+    /// The service manager notifier, if one was set.
+    #[cfg(unix)]
+    pub(crate) systemd_notifier: Option<SystemdNotifier>,
+}
+
+/// Holds the live counters of a running server.
+///
+/// The counters are shared between the accept loop, every connection task
+/// and the `ServerControlHook`, so they are updated without locking.
+pub(crate) struct ServerMetrics {
+    /// Whether the accept loop is still running.
+    pub(crate) running: AtomicBool,
+    /// The total number of accepted connections.
+    pub(crate) accepted_connections: AtomicU64,
+    /// The number of connections currently being processed.
+    pub(crate) active_connections: AtomicU64,
+    /// The total number of connections closed by a read error, whether reading the PROXY
+    /// header, the first request or a later pipelined or multiplexed frame.
+    pub(crate) read_errors: AtomicU64,
+    /// The total number of hook tasks that panicked.
+    pub(crate) task_panics: AtomicU64,
+    /// Notified whenever the last active connection finishes.
+    pub(crate) idle: Notify,
+    /// Duplicated handles of the listeners currently accepting, kept for handover.
+    pub(crate) listeners: std::sync::Mutex<Vec<StdTcpListener>>,
+    /// The instant at which the server started.
+    pub(crate) started_at: Instant,
+    /// The pool the connection read buffers are borrowed from.
+    pub(crate) buffer_pool: BufferPool,
+}
+
+/// A point-in-time snapshot of the server counters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ServerStats {
+    /// The total number of accepted connections.
+    pub(crate) accepted_connections: u64,
+    /// The number of connections currently being processed.
+    pub(crate) active_connections: u64,
+    /// The total number of connections closed by a read error, whether reading the PROXY
+    /// header, the first request or a later pipelined or multiplexed frame.
+    pub(crate) read_errors: u64,
+    /// The total number of hook tasks that panicked.
+    pub(crate) task_panics: u64,
+    /// The time elapsed since the server started.
+    pub(crate) uptime: Duration,
+    /// The counters of the read buffer pool.
+    pub(crate) buffer_pool: BufferPoolStats,
+}
+
 /// Represents the hooks for managing the server's lifecycle, specifically for waiting and shutting down.
 #[derive(Clone)]
 pub struct ServerControlHook {
-    /// A hook that returns a future, which completes when the server's main task finishes.
-    pub(crate) wait_hook:
-        Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> + Send + Sync>,
+    /// A hook that returns a future, which completes with the shutdown outcome when the server's main task finishes.
+    pub(crate) wait_hook: ServerWaitHook,
     /// A hook that, when called, initiates a graceful shutdown of the server.
-    pub(crate) shutdown_hook:
-        Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> + Send + Sync>,
+    pub(crate) shutdown_hook: ServerShutdownHook,
+    /// The live counters of the server controlled by this hook.
+    pub(crate) metrics: Arc<ServerMetrics>,
+    /// The server whose configuration and handlers are reloaded.
+    pub(crate) server: Server,
+    /// Sends replacement listeners to the running accept loop.
+    pub(crate) rebind_sender: UnboundedSender<Vec<StdTcpListener>>,
+    /// Publishes the server state snapshot new connections are handled with.
+    pub(crate) data_sender: Sender<Arc<ServerData>>,
+}
+
+/// Tracks the accept tasks of a running server across listener swaps.
+pub(crate) struct ListenerGroup {
+    /// The running accept tasks.
+    pub(crate) tasks: JoinSet<ShutdownResult>,
+    /// The generation each accept task was started in.
+    pub(crate) task_generation: HashMap<tokio::task::Id, u64>,
+    /// The generation of the listeners currently accepting.
+    pub(crate) generation: u64,
+    /// Announces generation changes so retired accept tasks stop.
+    pub(crate) generation_sender: Sender<u64>,
+}
+
+/// The hooks shared by the requests of a pipelined or multiplexed connection.
+pub(crate) struct ConnectionHooks {
+    /// The list of hooks to process.
+    pub(crate) hook: ServerHookList,
+    /// The list of panic handlers.
+    pub(crate) task_panic: ServerHookList,
+    /// The PROXY protocol header of the connection, if decoded.
+    pub(crate) proxy_header: Option<ProxyHeader>,
 }
@@ -0,0 +1,15 @@
+use super::*;
+
+/// A type alias for the outcome reported by `ServerControlHook::wait`.
+pub type ShutdownResult = Result<ShutdownReason, ServerError>;
+
+/// A type alias for the hook that waits for the server to finish.
+pub type ServerWaitHook =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ShutdownResult> + Send + 'static>> + Send + Sync>;
+
+/// A type alias for the hook that initiates a server shutdown with the given reason.
+pub type ServerShutdownHook =
+    Arc<dyn Fn(ShutdownReason) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> + Send + Sync>;
+
+/// A type alias for a list of pre-opened listeners the server accepts on instead of binding.
+pub type InheritedListenerList = Vec<Arc<StdTcpListener>>;
@@ -0,0 +1,214 @@
+use super::*;
+
+/// Writes a whole buffer through a shared socket reference.
+///
+/// Waiting for the socket to become writable does not need exclusive access, so a
+/// read waiting for data on the same connection is not blocked.
+///
+/// # Arguments
+///
+/// - `&TcpStream` - The socket to write to.
+/// - `&[u8]` - The bytes to write.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Ok(()) once everything is written, or the write error.
+pub(super) async fn write_all(stream: &TcpStream, mut data: &[u8]) -> std::io::Result<()> {
+    while !data.is_empty() {
+        stream.writable().await?;
+        match stream.try_write(data) {
+            Ok(0) => return Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(written) => data = &data[written..],
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// Writes every slice through a shared socket reference, retrying on partial vectored writes.
+///
+/// # Arguments
+///
+/// - `&TcpStream` - The socket to write to.
+/// - `&mut [IoSlice<'_>]` - The buffers in write order; empty ones are skipped.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Ok(()) once everything is written, or the write error.
+pub(super) async fn write_all_vectored(
+    stream: &TcpStream,
+    mut slices: &mut [IoSlice<'_>],
+) -> std::io::Result<()> {
+    IoSlice::advance_slices(&mut slices, 0);
+    while !slices.is_empty() {
+        stream.writable().await?;
+        match stream.try_write_vectored(slices) {
+            Ok(0) => return Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(written) => IoSlice::advance_slices(&mut slices, written),
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// Writes the chunks of a streamed body with the requested framing.
+///
+/// # Arguments
+///
+/// - `&TcpStream` - The socket to write to.
+/// - `&mut BoxChunkStream` - The source of the chunks.
+/// - `ChunkFraming` - How the chunks are delimited.
+/// - `&mut TransferReport` - The report updated after each chunk.
+///
+/// # Returns
+///
+/// - `ResponseResult` - Ok(()) once the body and its trailer are written, or the error that stopped it.
+pub(super) async fn write_chunks(
+    stream: &TcpStream,
+    source: &mut BoxChunkStream,
+    framing: ChunkFraming,
+    report: &mut TransferReport,
+) -> ResponseResult {
+    while let Some(item) =
+        std::future::poll_fn(|cx: &mut std::task::Context<'_>| source.as_mut().poll_next(cx)).await
+    {
+        let chunk: ResponseData =
+            item.map_err(|error: std::io::Error| ResponseError::StreamError(error.into()))?;
+        if chunk.is_empty() {
+            continue;
+        }
+        let prefix: Vec<u8> = match framing {
+            ChunkFraming::Raw => Vec::new(),
+            ChunkFraming::LengthPrefixed => u32::try_from(chunk.len())
+                .map_err(|_| {
+                    ResponseError::StreamError(
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "chunk does not fit a u32 length prefix",
+                        )
+                        .into(),
+                    )
+                })?
+                .to_be_bytes()
+                .to_vec(),
+            ChunkFraming::Chunked => format!("{:x}\r\n", chunk.len()).into_bytes(),
+        };
+        let suffix: &[u8] = match framing {
+            ChunkFraming::Chunked => b"\r\n",
+            _ => b"",
+        };
+        write_all_vectored(
+            stream,
+            &mut [
+                IoSlice::new(&prefix),
+                IoSlice::new(&chunk),
+                IoSlice::new(suffix),
+            ],
+        )
+        .await
+        .map_err(ResponseError::write)?;
+        report.bytes += chunk.len() as u64;
+        report.chunks += 1;
+    }
+    let trailer: &[u8] = match framing {
+        ChunkFraming::Raw => b"",
+        ChunkFraming::LengthPrefixed => &[0; 4],
+        ChunkFraming::Chunked => LAST_CHUNK_BYTES,
+    };
+    write_all_vectored(stream, &mut [IoSlice::new(trailer)])
+        .await
+        .map_err(ResponseError::write)
+}
+
+/// Sends a file range with Linux `sendfile`, letting the kernel copy the pages.
+///
+/// # Arguments
+///
+/// - `&TcpStream` - The socket to write to.
+/// - `&ResponseFile` - The file range to send.
+///
+/// # Returns
+///
+/// - `std::io::Result<Option<u64>>` - The bytes sent, or `None` when the file does not
+///   support `sendfile` and nothing was sent.
+#[cfg(target_os = "linux")]
+pub(super) async fn sendfile(
+    stream: &TcpStream,
+    file: &ResponseFile,
+) -> std::io::Result<Option<u64>> {
+    let mut offset: libc::off_t = file.offset as libc::off_t;
+    let mut sent: u64 = 0;
+    while sent < file.len {
+        let count: usize = (file.len - sent).min(MAX_SENDFILE_CHUNK) as usize;
+        stream.writable().await?;
+        let result: std::io::Result<usize> = stream.try_io(Interest::WRITABLE, || {
+            // SAFETY: both descriptors stay open for the whole call, as `stream` and `file`
+            // are borrowed for the duration of this function and own them. `offset` is a
+            // live, exclusively borrowed local the kernel advances past the bytes it sent,
+            // and `count` never exceeds the bytes left in the range.
+            let written: isize = unsafe {
+                libc::sendfile(
+                    stream.as_raw_fd(),
+                    file.file.as_raw_fd(),
+                    &mut offset,
+                    count,
+                )
+            };
+            if written < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(written as usize)
+        });
+        match result {
+            Ok(0) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+            Ok(written) => sent += written as u64,
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(error)
+                if sent == 0
+                    && matches!(error.raw_os_error(), Some(libc::EINVAL | libc::ENOSYS)) =>
+            {
+                return Ok(None);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(Some(sent))
+}
+
+/// Sends a file range by reading it in chunks on the blocking pool.
+///
+/// # Arguments
+///
+/// - `&TcpStream` - The socket to write to.
+/// - `&ResponseFile` - The file range to send.
+///
+/// # Returns
+///
+/// - `TransferResult` - The bytes sent, or the file or write error.
+pub(super) async fn copy_file(stream: &TcpStream, file: &ResponseFile) -> TransferResult {
+    let mut sent: u64 = 0;
+    while sent < file.len {
+        let handle: Arc<std::fs::File> = Arc::clone(&file.file);
+        let position: u64 = file.offset + sent;
+        let size: usize = (file.len - sent).min(FILE_COPY_CHUNK_SIZE as u64) as usize;
+        let chunk: Vec<u8> =
+            tokio::task::spawn_blocking(move || read_file_at(&handle, position, size))
+                .await
+                .map_err(|error: JoinError| {
+                    ResponseError::FileError(std::io::Error::other(error).into())
+                })?
+                .map_err(|error: std::io::Error| ResponseError::FileError(error.into()))?;
+        if chunk.is_empty() {
+            return Err(ResponseError::FileError(
+                std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into(),
+            ));
+        }
+        write_all(stream, &chunk)
+            .await
+            .map_err(ResponseError::write)?;
+        sent += chunk.len() as u64;
+    }
+    Ok(sent)
+}
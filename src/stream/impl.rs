@@ -11,7 +11,17 @@ impl ArcRwLockStream {
     ///
     /// - `Self` - A new ArcRwLockStream instance.
     pub fn from(arc_rw_lock_stream: ArcRwLock<TcpStream>) -> Self {
-        Self(arc_rw_lock_stream)
+        let (peer_addr, local_addr): (OptionSocketAddr, OptionSocketAddr) =
+            match arc_rw_lock_stream.try_read() {
+                Ok(stream) => (stream.peer_addr().ok(), stream.local_addr().ok()),
+                Err(_) => (None, None),
+            };
+        Self {
+            stream: arc_rw_lock_stream,
+            peer_addr,
+            local_addr,
+            writer: Arc::new(Mutex::new(())),
+        }
     }
 
     /// Creates a new `ArcRwLockStream` from a raw `TcpStream`.
@@ -24,7 +34,12 @@ impl ArcRwLockStream {
     ///
     /// - `Self` - A new ArcRwLockStream instance.
     pub fn from_stream(stream: TcpStream) -> Self {
-        Self(Arc::new(RwLock::new(stream)))
+        Self {
+            peer_addr: stream.peer_addr().ok(),
+            local_addr: stream.local_addr().ok(),
+            stream: Arc::new(RwLock::new(stream)),
+            writer: Arc::new(Mutex::new(())),
+        }
     }
 
     /// Acquires a read lock on the inner TCP stream.
@@ -33,7 +48,7 @@ impl ArcRwLockStream {
     ///
     /// - `ArcRwLockReadGuard<'_, TcpStream>` - A read guard for the TCP stream.
     pub async fn read(&self) -> ArcRwLockReadGuard<'_, TcpStream> {
-        self.0.read().await
+        self.stream.read().await
     }
 
     /// Acquires a write lock on the inner TCP stream.
@@ -42,7 +57,7 @@ impl ArcRwLockStream {
     ///
     /// - `ArcRwLockWriteGuard<'_, TcpStream>` - A write guard for the TCP stream.
     pub async fn write(&self) -> ArcRwLockWriteGuard<'_, TcpStream> {
-        self.0.write().await
+        self.stream.write().await
     }
 
     /// Attempts to send data through the stream.
@@ -58,12 +73,11 @@ impl ArcRwLockStream {
     where
         D: AsRef<[u8]>,
     {
-        let mut stream: ArcRwLockWriteGuard<'_, TcpStream> = self.write().await;
-        stream
-            .write_all(data.as_ref())
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
+        let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
+        write_all(&stream, data.as_ref())
             .await
-            .map_err(|error: std::io::Error| ResponseError::WriteError(error.to_string()))?;
-        Ok(())
+            .map_err(ResponseError::write)
     }
 
     /// Sends data through the stream.
@@ -82,18 +96,151 @@ impl ArcRwLockStream {
         self.try_send(data).await.unwrap();
     }
 
+    /// Attempts to send several buffers back to back under a single lock.
+    ///
+    /// The buffers are written with vectored writes, so they usually leave in one syscall
+    /// without being copied into a joint buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = D>` - The buffers in write order.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Ok(()) on success, or an error on failure.
+    pub async fn try_send_all<I, D>(&self, data: I) -> ResponseResult
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        let buffers: Vec<D> = data.into_iter().collect();
+        let mut slices: Vec<IoSlice<'_>> = buffers
+            .iter()
+            .map(|buffer: &D| IoSlice::new(buffer.as_ref()))
+            .filter(|slice: &IoSlice<'_>| !slice.is_empty())
+            .collect();
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
+        let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
+        write_all_vectored(&stream, &mut slices)
+            .await
+            .map_err(ResponseError::write)
+    }
+
+    /// Sends several buffers back to back under a single lock.
+    ///
+    /// # Arguments
+    ///
+    /// - `I: IntoIterator<Item = D>` - The buffers in write order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write operation fails.
+    pub async fn send_all<I, D>(&self, data: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        self.try_send_all(data).await.unwrap();
+    }
+
+    /// Attempts to send a file range without loading it into memory.
+    ///
+    /// On Linux the kernel copies the pages with `sendfile`; when the file does not
+    /// support it, or on other platforms, the range is copied in chunks instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseFile` - The file range to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of bytes sent, or an error on failure.
+    pub async fn try_send_file(&self, file: &ResponseFile) -> TransferResult {
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
+        let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
+        #[cfg(target_os = "linux")]
+        match sendfile(&stream, file).await {
+            Ok(Some(sent)) => return Ok(sent),
+            Ok(None) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                return Err(ResponseError::FileError(error.into()));
+            }
+            Err(error) => return Err(ResponseError::write(error)),
+        }
+        copy_file(&stream, file).await
+    }
+
+    /// Sends a file range without loading it into memory.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseFile` - The file range to send.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The number of bytes sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transfer fails.
+    pub async fn send_file(&self, file: &ResponseFile) -> u64 {
+        self.try_send_file(file).await.unwrap()
+    }
+
+    /// Sends a streamed body, pulling each chunk only after the previous one is written.
+    ///
+    /// The writer lock is held for the whole transfer so the framing is not interleaved
+    /// with other writes. Errors are reported in the returned `TransferReport`, and when
+    /// the source fails mid-stream the framing trailer is not written.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseStream` - The body to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferReport` - The bytes and chunks written and the error that stopped the transfer, if any.
+    pub async fn send_stream(&self, body: &ResponseStream) -> TransferReport {
+        let mut report: TransferReport = TransferReport::default();
+        let Some(mut source) = body.source.lock().await.take() else {
+            report.error = Some(ResponseError::StreamConsumed);
+            return report;
+        };
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
+        let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
+        report.error = write_chunks(&stream, &mut source, body.framing, &mut report)
+            .await
+            .err();
+        report
+    }
+
+    /// Attempts to send a streamed body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseStream` - The body to send.
+    ///
+    /// # Returns
+    ///
+    /// - `TransferResult` - The number of payload bytes sent, or the error that stopped the transfer.
+    pub async fn try_send_stream(&self, body: &ResponseStream) -> TransferResult {
+        self.send_stream(body).await.into_result()
+    }
+
     /// Attempts to flush the stream.
     ///
+    /// The sends in progress finish first. A pending read parks without the lock, so
+    /// it does not hold the flush up.
+    ///
     /// # Returns
     ///
     /// - `ResponseResult` - Ok(()) on success, or an error on failure.
     pub async fn try_flush(&self) -> ResponseResult {
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
         let mut stream: ArcRwLockWriteGuard<'_, TcpStream> = self.write().await;
-        stream
-            .flush()
+        AsyncWriteExt::flush(&mut *stream)
             .await
-            .map_err(|error: std::io::Error| ResponseError::FlushError(error.to_string()))?;
-        Ok(())
+            .map_err(ResponseError::flush)
     }
 
     /// Flushes the stream.
@@ -111,8 +258,10 @@ impl ArcRwLockStream {
     ///
     /// - `OptionSocketAddr` - The peer address if available.
     pub async fn try_get_peer_addr(&self) -> OptionSocketAddr {
-        let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
-        stream.peer_addr().ok()
+        if self.peer_addr.is_some() {
+            return self.peer_addr;
+        }
+        self.read().await.peer_addr().ok()
     }
 
     /// Gets the peer address.
@@ -128,18 +277,80 @@ impl ArcRwLockStream {
         self.try_get_peer_addr().await.unwrap()
     }
 
-    /// Attempts to shut down the stream.
+    /// Attempts to get the local address.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionSocketAddr` - The local address if available.
+    pub async fn try_get_local_addr(&self) -> OptionSocketAddr {
+        if self.local_addr.is_some() {
+            return self.local_addr;
+        }
+        self.read().await.local_addr().ok()
+    }
+
+    /// Gets the local address.
+    ///
+    /// # Returns
+    ///
+    /// - `SocketAddr` - The local address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the local address is not available.
+    pub async fn get_local_addr(&self) -> SocketAddr {
+        self.try_get_local_addr().await.unwrap()
+    }
+
+    /// Attempts to shut down the writing half of the stream.
+    ///
+    /// The sends in progress finish first. A pending read parks without the lock, so
+    /// it does not hold the shutdown up.
     ///
     /// # Returns
     ///
     /// - `ResponseResult` - Ok(()) on success, or an error on failure.
     pub async fn try_shutdown(&self) -> ResponseResult {
+        let _writer: MutexGuard<'_, ()> = self.writer.lock().await;
         let mut stream: ArcRwLockWriteGuard<'_, TcpStream> = self.write().await;
-        stream
-            .shutdown()
+        AsyncWriteExt::shutdown(&mut *stream)
             .await
-            .map_err(|error: std::io::Error| ResponseError::WriteError(error.to_string()))?;
-        Ok(())
+            .map_err(ResponseError::write)
+    }
+
+    /// Waits until the stream is readable.
+    ///
+    /// The shared lock is awaited, then held only while registering for readiness and
+    /// released before parking, so a read parked here does not hold up the writers of
+    /// the stream and waits for a held write lock without spinning. The readiness
+    /// registration wakes the parked task, which then polls again.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok(()) once readable, or the socket error.
+    pub(crate) async fn readable(&self) -> std::io::Result<()> {
+        loop {
+            let ready: Poll<std::io::Result<()>> = {
+                let stream: ArcRwLockReadGuard<'_, TcpStream> = self.read().await;
+                std::future::poll_fn(|cx: &mut std::task::Context<'_>| {
+                    Poll::Ready(stream.poll_read_ready(cx))
+                })
+                .await
+            };
+            if let Poll::Ready(result) = ready {
+                return result;
+            }
+            let mut parked: bool = false;
+            std::future::poll_fn(|_: &mut std::task::Context<'_>| {
+                if parked {
+                    Poll::Ready(())
+                } else {
+                    parked = true;
+                    Poll::Pending
+                }
+            })
+            .await;
+        }
     }
 
     /// Shuts down the stream.
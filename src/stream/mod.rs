@@ -1,7 +1,8 @@
+mod r#fn;
 mod r#impl;
 mod r#struct;
 mod r#type;
 
 pub use {r#struct::*, r#type::*};
 
-use super::*;
+use {super::*, r#fn::*};
@@ -4,5 +4,18 @@ use super::*;
 ///
 /// This structure provides safe concurrent access to a TCP stream,
 /// allowing multiple tasks to read from and write to the stream.
+/// The peer and local addresses are captured when the stream is wrapped,
+/// so reading them does not take the lock. Sends and reads only share the
+/// lock, so a read waiting for data does not hold up a send; sends are
+/// serialized by a separate writer lock.
 #[derive(Clone, Debug)]
-pub struct ArcRwLockStream(pub(super) ArcRwLock<TcpStream>);
+pub struct ArcRwLockStream {
+    /// The shared stream.
+    pub(super) stream: ArcRwLock<TcpStream>,
+    /// The peer address captured when the stream was wrapped.
+    pub(super) peer_addr: OptionSocketAddr,
+    /// The local address captured when the stream was wrapped.
+    pub(super) local_addr: OptionSocketAddr,
+    /// Serializes sends so the bytes of concurrent sends are not interleaved.
+    pub(super) writer: Arc<Mutex<()>>,
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+impl SystemdNotifier {
+    /// Creates a notifier that sends to the given socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<String>` - The socket path, or an abstract name prefixed with `@`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new notifier without a watchdog.
+    pub fn new<S>(socket: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            socket: socket.into(),
+            watchdog: None,
+        }
+    }
+
+    /// Creates a notifier from `NOTIFY_SOCKET`, `WATCHDOG_USEC` and `WATCHDOG_PID`.
+    ///
+    /// The watchdog interval is half of the configured timeout, as recommended by systemd.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Self>` - The notifier if `NOTIFY_SOCKET` is set, otherwise `None`.
+    pub fn from_env() -> Option<Self> {
+        let socket: String = std::env::var(NOTIFY_SOCKET_ENV).ok()?;
+        if socket.is_empty() {
+            return None;
+        }
+        let pid_matches: bool = match std::env::var(WATCHDOG_PID_ENV) {
+            Ok(pid) => pid.trim().parse::<u32>().ok() == Some(std::process::id()),
+            Err(_) => true,
+        };
+        let watchdog: Option<Duration> = std::env::var(WATCHDOG_USEC_ENV)
+            .ok()
+            .and_then(|usec: String| usec.trim().parse::<u64>().ok())
+            .filter(|usec: &u64| pid_matches && *usec > 0)
+            .map(|usec: u64| Duration::from_micros(usec / 2));
+        Some(Self { socket, watchdog })
+    }
+
+    /// Sets the interval at which watchdog notifications are sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The interval, or `None` to disable watchdog notifications.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated notifier.
+    pub fn watchdog(mut self, interval: Option<Duration>) -> Self {
+        self.watchdog = interval;
+        self
+    }
+
+    /// Gets the notification socket.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The socket path or abstract name.
+    pub fn get_socket(&self) -> &str {
+        &self.socket
+    }
+
+    /// Gets the watchdog interval.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The interval if watchdog notifications are enabled.
+    pub fn get_watchdog(&self) -> Option<Duration> {
+        self.watchdog
+    }
+
+    /// Sends a raw notification such as `READY=1`.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The newline-separated assignments to send.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+    pub fn notify<S>(&self, state: S) -> std::io::Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let socket: StdUnixDatagram = StdUnixDatagram::unbound()?;
+        #[cfg(target_os = "linux")]
+        if let Some(name) = self.socket.strip_prefix('@') {
+            use std::os::linux::net::SocketAddrExt;
+            let addr: std::os::unix::net::SocketAddr =
+                std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+            socket.send_to_addr(state.as_ref().as_bytes(), &addr)?;
+            return Ok(());
+        }
+        socket.send_to(state.as_ref().as_bytes(), &self.socket)?;
+        Ok(())
+    }
+
+    /// Notifies the service manager that startup has finished.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+    pub fn ready(&self) -> std::io::Result<()> {
+        self.notify(NOTIFY_READY)
+    }
+
+    /// Notifies the service manager that shutdown has begun.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+    pub fn stopping(&self) -> std::io::Result<()> {
+        self.notify(NOTIFY_STOPPING)
+    }
+
+    /// Sends a watchdog keep-alive notification.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<()>` - Ok(()) on success, or the OS error on failure.
+    pub fn ping_watchdog(&self) -> std::io::Result<()> {
+        self.notify(NOTIFY_WATCHDOG)
+    }
+}
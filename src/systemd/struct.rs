@@ -0,0 +1,11 @@
+/// Sends service manager notifications over the `NOTIFY_SOCKET` datagram socket.
+///
+/// This mirrors `sd_notify` without linking against libsystemd. Any local
+/// datagram socket can stand in for the service manager.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SystemdNotifier {
+    /// The notification socket path, or an abstract name prefixed with `@`.
+    pub(crate) socket: String,
+    /// The interval at which watchdog notifications are sent.
+    pub(crate) watchdog: Option<std::time::Duration>,
+}
@@ -0,0 +1,182 @@
+use super::*;
+
+#[tokio::test]
+async fn test_config_from_vars() {
+    let vars: Vec<(&str, &str)> = vec![
+        ("TCPLANE_HOST", "127.0.0.1"),
+        ("TCPLANE_PORT", "61000"),
+        ("TCPLANE_PROXY_PROTOCOL", "true"),
+        ("TCPLANE_TRUSTED_PROXIES", "10.0.0.0/8, ::1"),
+        ("TCPLANE_FRAMING", "delimiter:\\r\\n"),
+        ("TCPLANE_STREAM_ID_SIZE", "2"),
+        ("TCPLANE_LOG_LEVEL", "debug"),
+        ("OTHER_PORT", "1"),
+    ];
+    let config: ServerConfig = ServerConfig::from_vars("TCPLANE", vars).unwrap();
+    assert_eq!(config.get_host().await, "127.0.0.1");
+    assert_eq!(config.get_port().await, 61000);
+    assert_eq!(config.get_buffer_size().await, DEFAULT_BUFFER_SIZE);
+    assert_eq!(
+        config.get_framing().await,
+        Framing::Delimiter(b"\r\n".to_vec())
+    );
+    assert_eq!(config.get_stream_id_size().await, 2);
+    assert!(config.get_proxy_protocol().await);
+    assert_eq!(
+        config.get_trusted_proxies().await,
+        vec!["10.0.0.0/8".parse().unwrap(), "::1".parse().unwrap()]
+    );
+    let error: ConfigError = ServerConfig::from_vars("TCPLANE", [("TCPLANE_PORT", "70000")])
+        .err()
+        .unwrap();
+    assert!(matches!(error, ConfigError::InvalidField { ref field, .. } if field == "port"));
+    let error: ConfigError = ServerConfig::from_vars("", [("PORT", "61000")])
+        .err()
+        .unwrap();
+    assert_eq!(error, ConfigError::EmptyPrefix);
+    let error: ConfigError = ServerConfig::from_vars(
+        "TCPLANE",
+        [
+            ("TCPLANE_PIPELINE_DEPTH", "8"),
+            ("TCPLANE_STREAM_ID_SIZE", "2"),
+        ],
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(error, ConfigError::ConflictingOptions(_)));
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_config_from_file() {
+    let dir: std::path::PathBuf = std::env::temp_dir();
+    let toml_path: std::path::PathBuf = dir.join(format!("tcplane-{}.toml", std::process::id()));
+    std::fs::write(
+        &toml_path,
+        "host = \"127.0.0.1\"\nport = 61001\ntrusted_proxies = [\"192.0.2.0/24\"]\n",
+    )
+    .unwrap();
+    let config: ServerConfig = ServerConfig::from_file(&toml_path).unwrap();
+    std::fs::remove_file(&toml_path).unwrap();
+    assert_eq!(config.get_host().await, "127.0.0.1");
+    assert_eq!(config.get_port().await, 61001);
+    assert_eq!(
+        config.get_trusted_proxies().await,
+        vec!["192.0.2.0/24".parse().unwrap()]
+    );
+    let json_path: std::path::PathBuf = dir.join(format!("tcplane-{}.json", std::process::id()));
+    std::fs::write(&json_path, r#"{"port": 61002, "buffer_size": 1024}"#).unwrap();
+    let config: ServerConfig = ServerConfig::from_file(&json_path).unwrap();
+    std::fs::remove_file(&json_path).unwrap();
+    assert_eq!(config.get_port().await, 61002);
+    assert_eq!(config.get_buffer_size().await, 1024);
+    std::fs::write(&json_path, r#"{"buffer_size": 0}"#).unwrap();
+    let error: ConfigError = ServerConfig::from_file(&json_path).err().unwrap();
+    std::fs::remove_file(&json_path).unwrap();
+    assert_eq!(error, ConfigError::ZeroBufferSize);
+    let error: ConfigError = ServerConfig::from_str_with_format(
+        r#"{"port": 61003, "trusted_proxies": ["10.0.0.0/99"]}"#,
+        ConfigFormat::Json,
+    )
+    .err()
+    .unwrap();
+    assert!(
+        matches!(error, ConfigError::InvalidField { ref field, .. } if field == "trusted_proxies")
+    );
+    let error: ConfigError = ServerConfig::from_str_with_format("prot = 1", ConfigFormat::Toml)
+        .err()
+        .unwrap();
+    assert!(matches!(error, ConfigError::InvalidField { ref field, .. } if field == "prot"));
+    assert!(matches!(
+        ServerConfig::from_file("config.yaml").err().unwrap(),
+        ConfigError::UnsupportedFormat(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_config_validate() {
+    let config: ServerConfig = ServerConfig::new();
+    assert_eq!(config.validate().await, Ok(()));
+    config.host("[::1]").await;
+    assert_eq!(config.validate().await, Ok(()));
+    config.host("localhost").await;
+    assert_eq!(config.validate().await, Ok(()));
+    config.host("not a host").await;
+    assert_eq!(
+        config.validate().await,
+        Err(ConfigError::InvalidHost("not a host".to_owned()))
+    );
+    config.host("127.0.0.1").await.buffer_size(0).await;
+    assert_eq!(config.validate().await, Err(ConfigError::ZeroBufferSize));
+    config
+        .buffer_size(4096)
+        .await
+        .framing(Framing::Delimiter(Vec::new()))
+        .await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::InvalidField { ref field, .. }) if field == "framing"
+    ));
+    config.framing(Framing::LengthPrefixed).await;
+    config
+        .pipeline_depth(4)
+        .await
+        .stream_request_body(true)
+        .await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::ConflictingOptions(_))
+    ));
+    config
+        .stream_request_body(false)
+        .await
+        .stream_id_size(4)
+        .await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::ConflictingOptions(_))
+    ));
+    config.pipeline_depth(0).await.stream_id_size(9).await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::InvalidField { ref field, .. }) if field == "stream_id_size"
+    ));
+    config.stream_id_size(4).await.max_streams(0).await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::InvalidField { ref field, .. }) if field == "max_streams"
+    ));
+    config.max_streams(8).await.framing(Framing::Raw).await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::ConflictingOptions(_))
+    ));
+    config
+        .framing(Framing::LengthPrefixed)
+        .await
+        .stream_id_size(0)
+        .await;
+    config.buffer_size(8192).await.max_frame_size(1024).await;
+    assert_eq!(
+        config.validate().await,
+        Err(ConfigError::MaxFrameTooSmall {
+            max_frame_size: 1024,
+            buffer_size: 8192,
+        })
+    );
+    config
+        .max_frame_size(65536)
+        .await
+        .proxy_protocol(true)
+        .await;
+    assert!(matches!(
+        config.validate().await,
+        Err(ConfigError::ConflictingOptions(_))
+    ));
+    let server: Server = Server::new();
+    server.server_config(config).await;
+    assert!(matches!(
+        server.run().await.err(),
+        Some(ServerError::Config(ConfigError::ConflictingOptions(_)))
+    ));
+}
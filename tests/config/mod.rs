@@ -0,0 +1,3 @@
+mod r#fn;
+
+use super::*;
@@ -0,0 +1,81 @@
+use super::*;
+
+const USER_ID: Key<u64> = Key::new("user_id");
+
+#[derive(Debug, Eq, PartialEq)]
+struct Session {
+    token: String,
+}
+
+#[tokio::test]
+async fn test_context_typed_attributes() {
+    let ctx: Context = Context::new();
+    ctx.insert(7_u32).await;
+    ctx.insert(Session {
+        token: "abc".to_owned(),
+    })
+    .await;
+    ctx.insert(Session {
+        token: "def".to_owned(),
+    })
+    .await;
+    assert_eq!(ctx.get::<u32>().await, Some(7));
+    assert_eq!(ctx.get::<u64>().await, None);
+    let session: Arc<Session> = ctx.get_arc::<Session>().await.unwrap();
+    assert_eq!(session.token, "def");
+    assert_eq!(
+        ctx.with(|session: &Session| session.token.len()).await,
+        Some(3)
+    );
+    assert!(ctx.contains::<Session>().await);
+    assert!(ctx.remove::<Session>().await.is_some());
+    assert!(!ctx.contains::<Session>().await);
+    ctx.set_key(&USER_ID, 42).await;
+    assert_eq!(ctx.try_get_key(&USER_ID).await, Some(42));
+    assert_eq!(ctx.try_get_data::<u64, _>(USER_ID.get_name()).await, None);
+    ctx.set_data(USER_ID.get_name(), "alice".to_owned()).await;
+    assert_eq!(ctx.try_get_key(&USER_ID).await, Some(42));
+    assert_eq!(
+        ctx.try_get_key(&Key::<u32>::new(USER_ID.get_name())).await,
+        None
+    );
+    assert_eq!(ctx.with_key(&USER_ID, |id: &u64| id * 2).await, Some(84));
+    assert_eq!(*ctx.try_get_key_arc(&USER_ID).await.unwrap(), 42);
+    ctx.clear_data().await;
+    assert_eq!(ctx.try_get_key(&USER_ID).await, None);
+    assert_eq!(ctx.get::<u32>().await, None);
+}
+
+#[tokio::test]
+async fn test_context_attribute_scopes() {
+    let ctx: Context = Context::new();
+    ctx.connection()
+        .await
+        .set_data("user", "alice".to_owned())
+        .await;
+    ctx.set_data("scratch", 1_u8).await;
+    ctx.request().await.insert(2_u16).await;
+    assert_eq!(ctx.try_get_data::<u8, _>("scratch").await, Some(1));
+    assert_eq!(ctx.try_get_data::<String, _>("user").await, None);
+    ctx.next_request(b"second".to_vec()).await;
+    assert_eq!(ctx.get_request().await, b"second".to_vec());
+    assert_eq!(ctx.try_get_data::<u8, _>("scratch").await, None);
+    assert_eq!(ctx.get::<u16>().await, None);
+    let connection: Attributes = ctx.connection().await;
+    assert_eq!(
+        connection.try_get_data::<String, _>("user").await,
+        Some("alice".to_owned())
+    );
+}
+
+#[tokio::test]
+async fn test_context_shared_without_locks() {
+    let ctx: Context = Context::new();
+    assert_ne!(ctx.get_id(), Context::new().get_id());
+    let clone: Context = ctx.clone();
+    assert_eq!(clone.get_id(), ctx.get_id());
+    clone.abort().await.set_request(b"ping".to_vec()).await;
+    assert!(ctx.is_aborted().await);
+    assert_eq!(ctx.get_request().await, b"ping".to_vec());
+    assert!(ctx.try_get_stream().await.is_none());
+}
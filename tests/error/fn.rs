@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn test_error_keeps_io_error() {
+    let io_error: IoError = std::io::Error::from_raw_os_error(104).into();
+    assert_eq!(io_error.try_get_raw_os_error(), Some(104));
+    let error: ServerError = ServerError::PeerReset(io_error.clone());
+    assert_eq!(error.clone(), error);
+    assert_eq!(
+        error.try_get_io_error_kind(),
+        Some(std::io::ErrorKind::ConnectionReset)
+    );
+    let source: &std::io::Error = std::error::Error::source(&error)
+        .and_then(|source: &(dyn std::error::Error + 'static)| source.downcast_ref())
+        .unwrap();
+    assert_eq!(source.raw_os_error(), Some(104));
+    let error: ResponseError =
+        ResponseError::WriteError(std::io::Error::from(std::io::ErrorKind::TimedOut).into());
+    assert_eq!(
+        error.try_get_io_error_kind(),
+        Some(std::io::ErrorKind::TimedOut)
+    );
+    assert!(std::error::Error::source(&error).is_some());
+    assert!(std::error::Error::source(&ResponseError::Terminated).is_none());
+    let error: ServerError = ConfigError::ZeroBufferSize.into();
+    assert!(std::error::Error::source(&error).is_some());
+    assert_eq!(error.try_get_io_error(), None);
+}
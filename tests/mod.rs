@@ -1,5 +1,24 @@
+mod config;
+mod context;
+mod error;
+mod pool;
+mod proxy;
+mod response;
 mod server;
 
 use tcplane::*;
 
-use std::io::{Error, Write, stderr};
+use std::{
+    io::{Error, Write, stderr},
+    net::{IpAddr, SocketAddr},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::RwLockWriteGuard,
+};
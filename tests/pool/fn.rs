@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_buffer_pool_reuse() {
+    let pool: BufferPool = BufferPool::new([4096, 1024, 0, 1024], 1);
+    assert_eq!(pool.get_classes(), vec![1024, 4096]);
+    let mut first: PooledBuffer = pool.acquire(512);
+    assert!(first.capacity() >= 1024);
+    first.extend_from_slice(b"request");
+    let request: bytes::Bytes = first.split().freeze();
+    let second: PooledBuffer = pool.acquire(1024);
+    drop(first);
+    drop(second);
+    let stats: BufferPoolStats = pool.stats();
+    assert_eq!(stats.get_misses(), 2);
+    assert_eq!(stats.get_returned(), 1);
+    assert_eq!(stats.get_discarded(), 1);
+    assert_eq!(stats.get_idle(), 1);
+    assert_eq!(request, &b"request"[..]);
+    let reused: PooledBuffer = pool.acquire(1000);
+    assert!(reused.is_empty());
+    assert_eq!(pool.stats().get_hits(), 1);
+    let oversized: PooledBuffer = pool.acquire(8192);
+    drop(oversized);
+    assert_eq!(pool.stats().get_misses(), 3);
+    assert_eq!(pool.stats().get_returned(), 1);
+}
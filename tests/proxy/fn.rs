@@ -0,0 +1,97 @@
+use super::*;
+
+#[test]
+fn test_proxy_decode_v1() {
+    let data: &[u8] = b"PROXY TCP4 203.0.113.7 192.0.2.1 51000 443\r\nGET";
+    let (header, length): (ProxyHeader, usize) = ProxyHeader::decode(data).unwrap().unwrap();
+    assert_eq!(length, data.len() - 3);
+    assert_eq!(header.get_version(), ProxyVersion::V1);
+    assert_eq!(header.get_command(), ProxyCommand::Proxy);
+    assert_eq!(
+        header.get_source(),
+        Some("203.0.113.7:51000".parse().unwrap())
+    );
+    assert_eq!(
+        header.get_destination(),
+        Some("192.0.2.1:443".parse().unwrap())
+    );
+    assert_eq!(ProxyHeader::decode(b"PROXY TCP4 203.0.113.7"), Ok(None));
+    assert!(ProxyHeader::decode(b"PROXY TCP4 nope 192.0.2.1 1 2\r\n").is_err());
+    assert!(ProxyHeader::decode(b"PROXYX TCP4 203.0.113.7 192.0.2.1 51000 443\r\n").is_err());
+    assert!(ProxyHeader::decode(b"GET / HTTP/1.1\r\n").is_err());
+}
+
+#[test]
+fn test_proxy_decode_v2_with_tlv() {
+    let mut data: Vec<u8> = b"\r\n\r\n\0\r\nQUIT\n".to_vec();
+    data.extend_from_slice(&[0x21, 0x21, 0x00, 0x2A]);
+    data.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8]);
+    data.extend_from_slice(&[0; 11]);
+    data.push(0x01);
+    data.extend_from_slice(&[0; 15]);
+    data.push(0x02);
+    data.extend_from_slice(&51000u16.to_be_bytes());
+    data.extend_from_slice(&443u16.to_be_bytes());
+    data.extend_from_slice(&[0x02, 0x00, 0x03]);
+    data.extend_from_slice(b"foo");
+    assert_eq!(ProxyHeader::decode(&data[..20]), Ok(None));
+    let (header, length): (ProxyHeader, usize) = ProxyHeader::decode(&data).unwrap().unwrap();
+    assert_eq!(length, data.len());
+    assert_eq!(header.get_version(), ProxyVersion::V2);
+    assert_eq!(header.get_command(), ProxyCommand::Proxy);
+    assert_eq!(
+        header.get_source(),
+        Some("[2001:db8::1]:51000".parse().unwrap())
+    );
+    assert_eq!(header.get_destination(), Some("[::2]:443".parse().unwrap()));
+    assert_eq!(header.try_get_tlv(0x02).unwrap().get_value(), b"foo");
+}
+
+#[test]
+fn test_ip_cidr_contains() {
+    let cidr: IpCidr = "10.0.0.0/8".parse().unwrap();
+    assert!(cidr.contains("10.1.2.3".parse().unwrap()));
+    assert!(cidr.contains("::ffff:10.1.2.3".parse().unwrap()));
+    assert!(!cidr.contains("11.0.0.1".parse().unwrap()));
+    assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+    assert_eq!(cidr.to_string(), "10.0.0.0/8");
+}
+
+#[test]
+fn test_ip_cidr_ipv4_mapped_network() {
+    let cidr: IpCidr = "::ffff:10.1.2.0/120".parse().unwrap();
+    assert_eq!(cidr.get_addr(), "10.1.2.0".parse::<IpAddr>().unwrap());
+    assert_eq!(cidr.get_prefix_len(), 24);
+    assert!(cidr.contains("10.1.2.3".parse().unwrap()));
+    assert!(cidr.contains("::ffff:10.1.2.3".parse().unwrap()));
+    assert!(!cidr.contains("10.1.3.1".parse().unwrap()));
+    assert!(!cidr.contains("2001:db8::1".parse().unwrap()));
+    let wide: IpCidr = "::ffff:0:0/64".parse().unwrap();
+    assert!(wide.contains("10.1.2.3".parse().unwrap()));
+    assert!(!wide.contains("2001:db8::1".parse().unwrap()));
+}
+
+#[tokio::test]
+async fn test_server_proxy_protocol() {
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60005).await;
+    server_config.proxy_protocol(true).await;
+    server_config
+        .trusted_proxy("127.0.0.0/8".parse().unwrap())
+        .await;
+    let server: Server = Server::new();
+    server.server_config(server_config).await;
+    server.hook::<AddressHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60005").await.unwrap();
+    client
+        .write_all(b"PROXY TCP4 203.0.113.7 192.0.2.1 51000 443\r\nhi\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, br#"203.0.113.7:51000 -> 192.0.2.1:443 b"hi""#);
+    server_control_hook.shutdown().await;
+    let _: ShutdownResult = server_control_hook.wait().await;
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+impl ServerHook for AddressHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let response: String = format!(
+            "{} -> {} {:?}",
+            ctx.get_socket_addr_string().await,
+            ctx.get_local_addr().await,
+            ctx.get_request().await
+        );
+        let _: () = ctx.send(response).await;
+    }
+}
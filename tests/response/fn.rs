@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn test_response_shares_bytes() {
+    let response: Response = Response::from(b"shared".to_vec());
+    let clone: Response = response.clone();
+    assert_eq!(
+        response.get_data().as_ptr(),
+        clone.get_data().as_ptr(),
+        "clones must not copy the payload"
+    );
+    assert_eq!(Response::from("shared").get_data(), response.get_data());
+    assert_eq!(Response::from(String::from("abc")).len(), 3);
+    let request: Request = Request::from(b"ab\r\ncd".to_vec());
+    let head: Request = request.slice(..2);
+    assert_eq!(head, b"ab"[..]);
+    assert_eq!(head.as_ptr(), request.as_ptr());
+}
+
+#[test]
+fn test_response_chunks() {
+    let body: Response = Response::from_chunks(["len:", "", "abc"]);
+    assert_eq!(body.get_chunks().len(), 3);
+    assert_eq!(body.len(), 7);
+    assert_eq!(body.get_data(), "len:abc");
+    let mut response: Response = Response::default();
+    response.push_chunk("a");
+    assert!(matches!(response.get_body(), ResponseBody::Data(_)));
+    response.push_chunk("b");
+    assert!(matches!(response.get_body(), ResponseBody::Chunks(_)));
+    assert_eq!(response.get_mut_data(), "ab");
+    assert_eq!(response.get_chunks().len(), 1);
+    assert!(response.clear().is_empty());
+}
+
+#[tokio::test]
+async fn test_response_file_range() {
+    let path: std::path::PathBuf =
+        std::env::temp_dir().join(format!("tcplane-file-range-{}", std::process::id()));
+    std::fs::write(&path, b"abcdef").unwrap();
+    let file: ResponseFile = ResponseFile::open(&path, 1..=3).unwrap();
+    assert_eq!((file.get_offset(), file.get_len()), (1, 3));
+    assert_eq!(ResponseFile::open(&path, 4..100).unwrap().get_len(), 2);
+    assert!(ResponseFile::open(&path, 7..).is_err());
+    let mut response: Response = Response::from_file(file);
+    assert_eq!(response.len(), 3);
+    assert!(response.get_chunks().is_empty());
+    assert!(response.get_data().is_empty());
+    assert_eq!(response.try_get_file_data().await.unwrap().unwrap(), "bcd");
+    assert!(Response::from("abc").try_get_file_data().await.is_none());
+    std::fs::write(&path, b"ab").unwrap();
+    assert_eq!(
+        response
+            .try_get_file_data()
+            .await
+            .unwrap()
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+    assert!(response.get_mut_data().is_empty());
+    assert!(response.get_file().is_none());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -18,7 +18,34 @@ async fn test_server_basic_usage() {
         tokio::time::sleep(std::time::Duration::from_secs(60)).await;
         server_control_hook_2.shutdown().await;
     });
-    server_control_hook_1.wait().await;
+    let _: ShutdownResult = server_control_hook_1.wait().await;
+}
+
+#[tokio::test]
+async fn test_server_control_hook_lifecycle() {
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60001).await;
+    let server: Server = Server::new();
+    server.server_config(server_config).await;
+    server.hook::<GreetingHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    assert!(server_control_hook.is_running());
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60001").await.unwrap();
+    client.write_all(b"ping\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"Hello from GreetingHandler!");
+    let stats: ServerStats = server_control_hook.stats();
+    assert_eq!(stats.get_accepted_connections(), 1);
+    assert_eq!(stats.get_read_errors(), 0);
+    assert_eq!(stats.get_task_panics(), 0);
+    server_control_hook.shutdown().await;
+    assert_eq!(
+        server_control_hook.wait().await,
+        Ok(ShutdownReason::Requested)
+    );
+    assert!(!server_control_hook.is_running());
 }
 
 #[tokio::test]
@@ -56,3 +83,765 @@ async fn test_server_task_panic() {
     let panic_count: usize = server.read().await.get_task_panic().len();
     assert_eq!(panic_count, 1);
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_server_handover_listeners() {
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60002).await;
+    let old_server: Server = Server::new();
+    old_server.server_config(server_config).await;
+    old_server.hook::<GreetingHandler>().await;
+    let old_control_hook: ServerControlHook = old_server.run().await.unwrap();
+    let (sender, receiver) = std::os::unix::net::UnixStream::pair().unwrap();
+    old_control_hook.handover(&sender).await.unwrap();
+    assert_eq!(
+        old_control_hook.wait().await,
+        Ok(ShutdownReason::HandedOver)
+    );
+    let new_server: Server = Server::new();
+    new_server.hook::<EchoHandler>().await;
+    assert_eq!(new_server.receive_listeners(&receiver).await, Ok(1));
+    let new_control_hook: ServerControlHook = new_server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60002").await.unwrap();
+    client.write_all(b"hi\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, br#"Echo: b"hi""#);
+    new_control_hook.shutdown().await;
+    assert_eq!(new_control_hook.wait().await, Ok(ShutdownReason::Requested));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_export_listen_fds_environment() {
+    let listener: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let script: &str =
+        r#"[ -S /dev/fd/3 ] && echo "$LISTEN_PID $$ $LISTEN_FDS ${LISTEN_FDNAMES-unset}""#;
+    let mut named: std::process::Command = std::process::Command::new("sh");
+    named
+        .args(["-c", script])
+        .env("LISTEN_PID", "1")
+        .env("LISTEN_FDNAMES", "stale");
+    export_named_listen_fds(
+        &mut named,
+        vec![("api".to_owned(), listener.try_clone().unwrap())],
+    );
+    let output: String = String::from_utf8(named.output().unwrap().stdout).unwrap();
+    let fields: Vec<&str> = output.split_whitespace().collect();
+    assert_eq!(fields[0], fields[1]);
+    assert_eq!(fields[2..], ["1", "api"]);
+    let mut unnamed: std::process::Command = std::process::Command::new("sh");
+    unnamed.args(["-c", script]).env("LISTEN_FDNAMES", "stale");
+    export_listen_fds(&mut unnamed, vec![listener]);
+    let output: String = String::from_utf8(unnamed.output().unwrap().stdout).unwrap();
+    let fields: Vec<&str> = output.split_whitespace().collect();
+    assert_eq!(fields[0], fields[1]);
+    assert_eq!(fields[2..], ["1", "unset"]);
+}
+
+#[tokio::test]
+async fn test_server_drain_waits_for_connections() {
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60003).await;
+    let server: Server = Server::new();
+    server.server_config(server_config).await;
+    server.hook::<GreetingHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60003").await.unwrap();
+    while server_control_hook.stats().get_active_connections() == 0 {
+        tokio::task::yield_now().await;
+    }
+    server_control_hook.drain().await;
+    let pending: Result<ShutdownResult, tokio::time::error::Elapsed> = tokio::time::timeout(
+        std::time::Duration::from_millis(200),
+        server_control_hook.wait(),
+    )
+    .await;
+    assert!(pending.is_err());
+    assert!(TcpStream::connect("127.0.0.1:60003").await.is_err());
+    client.write_all(b"ping\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"Hello from GreetingHandler!");
+    assert_eq!(
+        server_control_hook.wait().await,
+        Ok(ShutdownReason::Drained)
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_server_systemd_notify() {
+    let socket_path: std::path::PathBuf =
+        std::env::temp_dir().join(format!("tcplane-notify-{}.sock", std::process::id()));
+    let _: std::io::Result<()> = std::fs::remove_file(&socket_path);
+    let notify_socket: tokio::net::UnixDatagram =
+        tokio::net::UnixDatagram::bind(&socket_path).unwrap();
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60004).await;
+    let server: Server = Server::new();
+    server.server_config(server_config).await;
+    server
+        .systemd_notifier(
+            SystemdNotifier::new(socket_path.to_string_lossy())
+                .watchdog(Some(std::time::Duration::from_millis(20))),
+        )
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut buffer: [u8; 64] = [0; 64];
+    let size: usize = notify_socket.recv(&mut buffer).await.unwrap();
+    assert_eq!(&buffer[..size], b"READY=1");
+    let size: usize = notify_socket.recv(&mut buffer).await.unwrap();
+    assert_eq!(&buffer[..size], b"WATCHDOG=1");
+    server_control_hook.shutdown().await;
+    let _: ShutdownResult = server_control_hook.wait().await;
+    loop {
+        let size: usize = notify_socket.recv(&mut buffer).await.unwrap();
+        if &buffer[..size] == b"STOPPING=1" {
+            break;
+        }
+        assert_eq!(&buffer[..size], b"WATCHDOG=1");
+    }
+    std::fs::remove_file(&socket_path).unwrap();
+}
+
+#[tokio::test]
+async fn test_server_reload_config_and_hooks() {
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config.host("127.0.0.1").await;
+    server_config.port(60006).await;
+    let server: Server = Server::new();
+    server.server_config(server_config.clone()).await;
+    server.hook::<GreetingHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut established: TcpStream = TcpStream::connect("127.0.0.1:60006").await.unwrap();
+    while server_control_hook.stats().get_active_connections() == 0 {
+        tokio::task::yield_now().await;
+    }
+    server_config.buffer_pool_capacity(1).await;
+    assert!(matches!(
+        server_control_hook.reload(&server_config).await,
+        Err(ServerError::Config(ConfigError::InvalidField { .. }))
+    ));
+    server_config
+        .buffer_pool_capacity(DEFAULT_BUFFER_POOL_CAPACITY)
+        .await;
+    server_config.port(60007).await;
+    server_control_hook.reload(&server_config).await.unwrap();
+    let replacement: Server = Server::new();
+    replacement.hook::<EchoHandler>().await;
+    server_control_hook.reload_hooks(&replacement).await;
+    let mut client: TcpStream = loop {
+        if let Ok(client) = TcpStream::connect("127.0.0.1:60007").await {
+            break client;
+        }
+        tokio::task::yield_now().await;
+    };
+    client.write_all(b"hi\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, br#"Echo: b"hi""#);
+    established.write_all(b"ping\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    established.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"Hello from GreetingHandler!");
+    assert!(TcpStream::connect("127.0.0.1:60006").await.is_err());
+    assert!(server_control_hook.is_running());
+    server_control_hook.shutdown().await;
+    assert_eq!(
+        server_control_hook.wait().await,
+        Ok(ShutdownReason::Requested)
+    );
+}
+
+#[tokio::test]
+async fn test_server_reload_inherited_listener_address() {
+    let listener: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let server: Arc<ServerData> = Server::builder().listener(listener).build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let server_config: ServerConfig = ServerConfig::new();
+    server_config
+        .max_frame_size(DEFAULT_MAX_FRAME_SIZE * 2)
+        .await;
+    server_control_hook.reload(&server_config).await.unwrap();
+    server_config.port(60025).await;
+    assert!(matches!(
+        server_control_hook.reload(&server_config).await,
+        Err(ServerError::Config(ConfigError::ConflictingOptions(_)))
+    ));
+    server_control_hook.shutdown().await;
+    assert_eq!(
+        server_control_hook.wait().await,
+        Ok(ShutdownReason::Requested)
+    );
+}
+
+#[tokio::test]
+async fn test_server_builder() {
+    let config: ServerConfigBuilder = ServerConfig::builder()
+        .host("127.0.0.1")
+        .port(60008)
+        .buffer_size(1024)
+        .max_frame_size(4096);
+    assert_eq!(config.validate(), Ok(()));
+    let server: Arc<ServerData> = Server::builder()
+        .config(config)
+        .hook::<GreetingHandler>()
+        .task_panic::<PanicHandler>()
+        .read_error::<ErrorHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60008").await.unwrap();
+    client.write_all(b"ping\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"Hello from GreetingHandler!");
+    server_control_hook.shutdown().await;
+    assert_eq!(
+        server_control_hook.wait().await,
+        Ok(ShutdownReason::Requested)
+    );
+    const BUILDER: ServerConfigBuilder = ServerConfigBuilder::new().port(60009).max_streams(8);
+    const SERVER_BUILDER: ServerBuilder = ServerBuilder::new();
+    let config: ServerConfig = BUILDER.build();
+    assert_eq!(config.get_port().await, 60009);
+    assert_eq!(config.get_max_streams().await, 8);
+    assert_eq!(
+        SERVER_BUILDER.build().get_config(),
+        &ServerConfigData::default()
+    );
+}
+
+#[tokio::test]
+async fn test_server_read_error_context() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60010)
+                .buffer_size(16)
+                .max_frame_size(16),
+        )
+        .hook::<GreetingHandler>()
+        .read_error::<ReadErrorReplyHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60010").await.unwrap();
+    client.write_all(&[b'a'; 17]).await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"FrameTooLarge(16) 127.0.0.1");
+    assert_eq!(server_control_hook.stats().get_read_errors(), 1);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_task_panic_details() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60011))
+        .hook::<DefaultHook>()
+        .hook::<BoomHandler>()
+        .task_panic::<PanicReportHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60011").await.unwrap();
+    client.write_all(b"ping\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(
+        String::from_utf8(response).unwrap(),
+        format!("1 {} boom 42", std::any::type_name::<BoomHandler>())
+    );
+    assert_eq!(server_control_hook.stats().get_task_panics(), 1);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_shared_state() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60012))
+        .state(HitCounter::default())
+        .hook::<CounterHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    for expected in ["1", "2"] {
+        let mut client: TcpStream = TcpStream::connect("127.0.0.1:60012").await.unwrap();
+        client.write_all(b"ping\r\n\r\n").await.unwrap();
+        let mut response: Vec<u8> = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        assert_eq!(response, expected.as_bytes());
+    }
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_buffer_pool() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60013)
+                .buffer_pool_capacity(4),
+        )
+        .hook::<EchoHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    for _ in 0..2 {
+        let mut client: TcpStream = TcpStream::connect("127.0.0.1:60013").await.unwrap();
+        client.write_all(b"hi\r\n\r\n").await.unwrap();
+        let mut response: Vec<u8> = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        assert_eq!(response, br#"Echo: b"hi""#);
+        while server_control_hook.stats().get_active_connections() > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    }
+    let stats: BufferPoolStats = server_control_hook.stats().get_buffer_pool();
+    assert_eq!(stats.get_misses(), 1);
+    assert_eq!(stats.get_hits(), 1);
+    assert_eq!(stats.get_idle(), 1);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_send_all() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60014))
+        .hook::<ChunkedHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60014").await.unwrap();
+    client.write_all(b"body\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"4:body;head-body");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_send_file() {
+    let path: std::path::PathBuf =
+        std::env::temp_dir().join(format!("tcplane-send-file-{}", std::process::id()));
+    std::fs::write(&path, b"0123456789").unwrap();
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60015))
+        .hook::<FileHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60015").await.unwrap();
+    client
+        .write_all(format!("{}\r\n\r\n", path.display()).as_bytes())
+        .await
+        .unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(response, b"23456789018");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_send_stream() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60016))
+        .hook::<StreamHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60016").await.unwrap();
+    client.write_all(b"go\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    let mut expected: Vec<u8> = b"b\r\nhello world\r\n0\r\n\r\n".to_vec();
+    expected.extend_from_slice(b"\0\0\0\x02ab\0\0\0\x01c\0\0\0\0");
+    expected.extend_from_slice(b"partial|11/1|true|7/true");
+    assert_eq!(response, expected);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_stream_request_body() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60017)
+                .stream_request_body(true),
+        )
+        .hook::<UploadHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60017").await.unwrap();
+    client.write_all(b"PUT /blob\r\n\r\nstart").await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let mut payload: Vec<u8> = vec![b'x'; 100_000];
+    payload.extend_from_slice(b"end");
+    client.write_all(&payload).await.unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"PUT /blob|100008|true");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_context_reads() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60018)
+                .framing(Framing::Delimiter(b"\n".to_vec())),
+        )
+        .hook::<ProtocolHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60018").await.unwrap();
+    client.write_all(b"hello\nabcxyz;next\n").await.unwrap();
+    let expected: &[u8] = b"hello|abc|xyz|next|true|";
+    let mut summary: Vec<u8> = vec![0; expected.len()];
+    client.read_exact(&mut summary).await.unwrap();
+    assert_eq!(summary, expected);
+    client.write_all(b"late\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"true|late");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_messages_in_one_write() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60019))
+        .hook::<FrameListHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60019").await.unwrap();
+    client
+        .write_all(b"one\r\n\r\ntwo\r\n\r\nthree\r\n\r\n")
+        .await
+        .unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"one,two,three");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_request_attributes_reset_per_frame() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60025))
+        .hook::<FrameScopeHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60025").await.unwrap();
+    client.write_all(b"one\r\n\r\ntwo\r\n\r\n").await.unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"one::alice,two::alice");
+    server_control_hook.shutdown().await;
+}
+
+/// Reads the CPU time the current thread has used, in clock ticks.
+#[cfg(target_os = "linux")]
+fn thread_cpu_ticks() -> u64 {
+    let stat: String = std::fs::read_to_string("/proc/thread-self/stat").unwrap();
+    let fields: Vec<&str> = stat
+        .rsplit_once(')')
+        .unwrap()
+        .1
+        .split_whitespace()
+        .collect();
+    fields[11].parse::<u64>().unwrap() + fields[12].parse::<u64>().unwrap()
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_server_reader_waits_for_held_writer() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60026)
+                .pipeline_depth(4),
+        )
+        .hook::<HeldWriterHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let started: u64 = thread_cpu_ticks();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60026").await.unwrap();
+    client.write_all(b"one\r\n\r\n").await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    client.write_all(b"two\r\n\r\n").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    while response.len() < b"one;two;".len() {
+        let mut chunk: [u8; 16] = [0; 16];
+        let read: usize = client.read(&mut chunk).await.unwrap();
+        assert_ne!(read, 0);
+        response.extend_from_slice(&chunk[..read]);
+    }
+    assert_eq!(response, b"one;two;");
+    let used: u64 = thread_cpu_ticks() - started;
+    assert!(used < 10, "the reader spun for {used} ticks");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_unterminated_request_gets_reply() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(ServerConfig::builder().host("127.0.0.1").port(60027))
+        .hook::<EchoHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60027").await.unwrap();
+    client.write_all(b"ping").await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.read_to_end(&mut response),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(response, br#"Echo: b"ping""#);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_message_split_byte_by_byte() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60020)
+                .framing(Framing::Terminated),
+        )
+        .hook::<FrameListHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60020").await.unwrap();
+    client.set_nodelay(true).unwrap();
+    for byte in b"hello\r\n\r\nworld\r\n\r\n" {
+        client.write_all(&[*byte]).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"hello,world");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_pipelining_keeps_response_order() {
+    let load: PipelineLoad = PipelineLoad::default();
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60021)
+                .pipeline_depth(3),
+        )
+        .state(load.clone())
+        .hook::<DelayedReplyHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60021").await.unwrap();
+    client
+        .write_all(b"60\r\n\r\n30\r\n\r\n0\r\n\r\n90\r\n\r\n")
+        .await
+        .unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    assert_eq!(response, b"60;30;0;90;");
+    assert_eq!(load.peak.load(Ordering::SeqCst), 3);
+    server_control_hook.shutdown().await;
+}
+
+fn tagged_frame(id: u32, payload: &[u8]) -> Vec<u8> {
+    let mut frame: Vec<u8> = ((payload.len() + 4) as u32).to_be_bytes().to_vec();
+    frame.extend_from_slice(&id.to_be_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+fn tagged_replies(mut response: &[u8]) -> Vec<(u32, String)> {
+    let mut replies: Vec<(u32, String)> = Vec::new();
+    while !response.is_empty() {
+        let len: usize = u32::from_be_bytes(response[..4].try_into().unwrap()) as usize;
+        let id: u32 = u32::from_be_bytes(response[4..8].try_into().unwrap());
+        replies.push((
+            id,
+            String::from_utf8_lossy(&response[8..4 + len]).into_owned(),
+        ));
+        response = &response[4 + len..];
+    }
+    replies
+}
+
+#[tokio::test]
+async fn test_server_multiplexed_streams() {
+    let probe: CancelProbe = CancelProbe::default();
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60022)
+                .framing(Framing::LengthPrefixed)
+                .stream_id_size(4),
+        )
+        .state(probe.clone())
+        .hook::<MultiplexHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60022").await.unwrap();
+    let frames: Vec<Vec<u8>> = vec![
+        tagged_frame(1, b"slow"),
+        tagged_frame(2, b"fast"),
+        tagged_frame(3, b"hold"),
+        tagged_frame(4, b"hang"),
+        tagged_frame(3, b"more"),
+        tagged_frame(5, b"bye"),
+    ];
+    client.write_all(&frames.concat()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    client.write_all(&tagged_frame(4, b"")).await.unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.read_to_end(&mut response),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let mut replies: Vec<(u32, String)> = tagged_replies(&response);
+    assert_eq!(replies.last().unwrap().0, 1);
+    replies.sort();
+    assert_eq!(
+        replies,
+        vec![
+            (1, "slow done".to_owned()),
+            (2, "fast done".to_owned()),
+            (3, "hold+more".to_owned()),
+            (5, String::new()),
+            (5, "bye".to_owned()),
+        ]
+    );
+    assert!(probe.0.load(Ordering::SeqCst));
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_pipelining_flush_while_reading() {
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60023)
+                .pipeline_depth(2),
+        )
+        .hook::<FlushingReplyHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60023").await.unwrap();
+    for message in ["one", "two"] {
+        client
+            .write_all(format!("{message}\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut reply: Vec<u8> = vec![0; message.len() + 1];
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.read_exact(&mut reply),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(reply, format!("{message};").as_bytes());
+    }
+    client.shutdown().await.unwrap();
+    let mut rest: Vec<u8> = Vec::new();
+    client.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_multiplexed_stream_limit() {
+    let load: PipelineLoad = PipelineLoad::default();
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60024)
+                .framing(Framing::LengthPrefixed)
+                .stream_id_size(4)
+                .max_streams(2),
+        )
+        .state(load.clone())
+        .hook::<DelayedReplyHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60024").await.unwrap();
+    let frames: Vec<Vec<u8>> = (1..=4).map(|id: u32| tagged_frame(id, b"30")).collect();
+    client.write_all(&frames.concat()).await.unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    let mut replies: Vec<(u32, String)> = tagged_replies(&response);
+    replies.sort();
+    let expected: Vec<(u32, String)> = (1..=4)
+        .flat_map(|id: u32| [(id, "30".to_owned()), (id, ";".to_owned())])
+        .collect();
+    assert_eq!(replies, expected);
+    assert_eq!(load.peak.load(Ordering::SeqCst), 2);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_multiplexed_file_chunks() {
+    let path: std::path::PathBuf =
+        std::env::temp_dir().join(format!("tcplane-multiplex-file-{}", std::process::id()));
+    std::fs::write(&path, b"abcdefghij").unwrap();
+    let server: Arc<ServerData> = Server::builder()
+        .config(
+            ServerConfig::builder()
+                .host("127.0.0.1")
+                .port(60028)
+                .buffer_size(4)
+                .framing(Framing::LengthPrefixed)
+                .stream_id_size(4),
+        )
+        .hook::<MultiplexFileHandler>()
+        .build();
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let mut client: TcpStream = TcpStream::connect("127.0.0.1:60028").await.unwrap();
+    client
+        .write_all(&tagged_frame(7, path.display().to_string().as_bytes()))
+        .await
+        .unwrap();
+    client.shutdown().await.unwrap();
+    let mut response: Vec<u8> = Vec::new();
+    tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.read_to_end(&mut response),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let expected: Vec<(u32, String)> = ["bcde", "fghi", "j", "9"]
+        .into_iter()
+        .map(|payload: &str| (7, payload.to_owned()))
+        .collect();
+    assert_eq!(tagged_replies(&response), expected);
+    server_control_hook.shutdown().await;
+}
@@ -38,9 +38,346 @@ impl ServerHook for ErrorHandler {
     }
 
     async fn handle(self, ctx: &Context) {
-        if let Some(error) = ctx.try_get_data::<String, _>("error").await {
-            eprintln!("{error}");
+        if let Some(error) = ctx.try_get_server_error().await {
+            eprintln!("{:?} {error}", ctx.try_get_socket_addr().await);
             let _: Result<(), Error> = Write::flush(&mut stderr());
         }
     }
 }
+
+impl ServerHook for ReadErrorReplyHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let peer: Option<SocketAddr> = ctx.try_get_socket_addr().await;
+        let error: ServerError = ctx.get_server_error().await;
+        let reply: String = format!("{error:?} {}", peer.unwrap().ip());
+        let _: () = ctx.send(reply).await;
+    }
+}
+
+impl ServerHook for BoomHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _: &Context) {
+        panic!("boom {}", 42);
+    }
+}
+
+impl ServerHook for PanicReportHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let panic: TaskPanic = ctx.get_task_panic().await;
+        let reply: String = format!(
+            "{} {} {}",
+            panic.get_hook_index(),
+            panic.try_get_hook_name().unwrap_or_default(),
+            panic.try_get_message().unwrap_or_default()
+        );
+        let _: () = ctx.send(reply).await;
+    }
+}
+
+impl ServerHook for CounterHandler {
+    async fn new(ctx: &Context) -> Self {
+        Self(ctx.state::<HitCounter>().await.unwrap())
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let hits: u64 = self.0.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+        let _: () = ctx.send(hits.to_string()).await;
+    }
+}
+
+impl ServerHook for ChunkedHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let header: String = format!("{}:", request.len());
+        let _: () = ctx.send_all([header.as_bytes(), &request, b";"]).await;
+        let mut response: Response = Response::from("head");
+        response.push_chunk("-").push_chunk(request);
+        let _: () = response.send(&ctx.get_stream().await).await;
+    }
+}
+
+impl ServerHook for FileHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let path: String = String::from_utf8_lossy(&request).into_owned();
+        let sent: u64 = ctx.send_file(&path, 2..).await;
+        let head: Response = Response::from_file(ResponseFile::open(&path, ..2).unwrap());
+        let _: () = head.send(&ctx.get_stream().await).await;
+        let _: () = ctx.send(sent.to_string()).await;
+    }
+}
+
+impl ServerHook for MultiplexFileHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let path: String = String::from_utf8_lossy(&request).into_owned();
+        let sent: u64 = ctx.send_file(&path, 1..).await;
+        let _: () = ctx.send(sent.to_string()).await;
+    }
+}
+
+impl futures_core::Stream for WordStream {
+    type Item = &'static str;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        std::task::Poll::Ready(self.0.pop_front())
+    }
+}
+
+impl tokio::io::AsyncRead for FailingReader {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        if self.0 {
+            return std::task::Poll::Ready(Err(Error::other("source failed")));
+        }
+        self.0 = true;
+        buf.put_slice(b"partial");
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+impl ServerHook for StreamHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let reader: std::io::Cursor<Vec<u8>> = std::io::Cursor::new(b"hello world".to_vec());
+        let report: TransferReport = ctx
+            .send_stream(ResponseStream::from_reader(reader).framing(ChunkFraming::Chunked))
+            .await;
+        let words: WordStream = WordStream(["ab", "", "c"].into());
+        let response: Response = Response::from_stream(
+            ResponseStream::from_stream(words).framing(ChunkFraming::LengthPrefixed),
+        );
+        let stream: ArcRwLockStream = ctx.get_stream().await;
+        let _: () = response.send(&stream).await;
+        let consumed: bool = response.try_send(&stream).await == Err(ResponseError::StreamConsumed);
+        let failed: TransferReport = ctx
+            .send_stream(ResponseStream::from_reader(FailingReader(false)))
+            .await;
+        let summary: String = format!(
+            "|{}/{}|{consumed}|{}/{}",
+            report.get_bytes(),
+            report.get_chunks(),
+            failed.get_bytes(),
+            matches!(failed.try_get_error(), Some(ResponseError::StreamError(_))),
+        );
+        let _: () = ctx.send(summary).await;
+    }
+}
+
+impl ServerHook for UploadHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let head: Request = ctx.get_request().await;
+        let mut body: RequestBody = ctx.get_request_body().await;
+        let first: Request = body.next_chunk().await.unwrap().unwrap_or_default();
+        let mut rest: Vec<u8> = Vec::new();
+        body.read_to_end(&mut rest).await.unwrap();
+        let summary: String = format!(
+            "{}|{}|{}",
+            String::from_utf8_lossy(&head),
+            first.len() + rest.len(),
+            rest.ends_with(b"end"),
+        );
+        let _: () = ctx.send(summary).await;
+    }
+}
+
+impl ServerHook for ProtocolHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let exact: Request = ctx.read_exact(3).await.unwrap();
+        let until: Request = ctx.read_until(";").await.unwrap();
+        let frame: Request = ctx.read_frame().await.unwrap().unwrap();
+        let timed_out: bool = matches!(
+            ctx.read_with_timeout(std::time::Duration::from_millis(50))
+                .await,
+            Err(ServerError::ReadTimeout(_))
+        );
+        let ctx_clone: Context = ctx.clone();
+        let pending: tokio::task::JoinHandle<Option<Request>> =
+            tokio::spawn(async move { ctx_clone.read_frame().await.unwrap() });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        let summary: String = [request, exact, until, frame]
+            .iter()
+            .map(|part: &Request| format!("{}|", String::from_utf8_lossy(part)))
+            .collect::<String>()
+            + &format!("{timed_out}|");
+        let sent: bool = tokio::time::timeout(std::time::Duration::from_secs(1), ctx.send(summary))
+            .await
+            .is_ok();
+        let late: Request = pending.await.unwrap().unwrap_or_default();
+        let _: () = ctx
+            .send(format!("{sent}|{}", String::from_utf8_lossy(&late)))
+            .await;
+    }
+}
+
+impl ServerHook for FrameListHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let mut frames: Vec<String> =
+            vec![String::from_utf8_lossy(&ctx.get_request().await).into_owned()];
+        while let Some(frame) = ctx.read_frame().await.unwrap() {
+            frames.push(String::from_utf8_lossy(&frame).into_owned());
+        }
+        let _: () = ctx.send(frames.join(",")).await;
+    }
+}
+
+impl ServerHook for FrameScopeHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        ctx.connection()
+            .await
+            .set_data("user", "alice".to_owned())
+            .await;
+        let mut replies: Vec<String> = Vec::new();
+        loop {
+            let scratch: Option<String> = ctx.try_get_data("scratch").await;
+            let user: Option<String> = ctx.connection().await.try_get_data("user").await;
+            let request: Request = ctx.get_request().await;
+            replies.push(format!(
+                "{}:{}:{}",
+                String::from_utf8_lossy(&request),
+                scratch.unwrap_or_default(),
+                user.unwrap_or_default()
+            ));
+            ctx.set_data("scratch", String::from_utf8_lossy(&request).into_owned())
+                .await;
+            if ctx.read_frame().await.unwrap().is_none() {
+                break;
+            }
+        }
+        let _: () = ctx.send(replies.join(",")).await;
+    }
+}
+
+impl ServerHook for HeldWriterHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let stream: ArcRwLockStream = ctx.get_stream().await;
+        let mut guard: RwLockWriteGuard<'_, TcpStream> = stream.write().await;
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        guard.write_all(&request).await.unwrap();
+        guard.write_all(b";").await.unwrap();
+    }
+}
+
+impl ServerHook for DelayedReplyHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let load: Arc<PipelineLoad> = ctx.state::<PipelineLoad>().await.unwrap();
+        let active: usize = load.active.fetch_add(1, Ordering::SeqCst) + 1;
+        load.peak.fetch_max(active, Ordering::SeqCst);
+        let request: Request = ctx.get_request().await;
+        let delay: u64 = String::from_utf8_lossy(&request).parse().unwrap();
+        let _: () = ctx.send(&request).await;
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        let _: () = ctx.send(";").await;
+        load.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl ServerHook for MultiplexHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        match &request[..] {
+            b"slow" => {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                let _: () = ctx.send("slow done").await;
+            }
+            b"hold" => {
+                let more: Request = ctx.read_frame().await.unwrap().unwrap();
+                let _: () = ctx.send_all([&request[..], b"+", &more[..]]).await;
+            }
+            b"hang" => {
+                let _guard: CancelGuard = CancelGuard(ctx.state::<CancelProbe>().await.unwrap());
+                std::future::pending::<()>().await;
+            }
+            b"bye" => {
+                let _: () = ctx.send("bye").await;
+                let _: () = ctx.shutdown().await;
+                assert_eq!(ctx.try_send("late").await, Err(ResponseError::Terminated));
+            }
+            _ => {
+                let reply: String = format!("{} done", String::from_utf8_lossy(&request));
+                let _: () = ctx.send(reply).await;
+            }
+        }
+    }
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.0.store(true, Ordering::SeqCst);
+    }
+}
+
+impl ServerHook for FlushingReplyHandler {
+    async fn new(_: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let _: () = ctx.flush().await;
+        let _: () = ctx.send_all([&request[..], b";"]).await;
+        let _: () = ctx.flush().await;
+    }
+}
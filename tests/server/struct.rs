@@ -1,3 +1,5 @@
+use super::*;
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct GreetingHandler;
 
@@ -9,3 +11,71 @@ pub(crate) struct PanicHandler;
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct ErrorHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ReadErrorReplyHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BoomHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PanicReportHandler;
+
+#[derive(Debug, Default)]
+pub(crate) struct HitCounter(pub(crate) std::sync::atomic::AtomicU64);
+
+#[derive(Debug)]
+pub(crate) struct CounterHandler(pub(crate) Arc<HitCounter>);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ChunkedHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FileHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MultiplexFileHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct StreamHandler;
+
+#[derive(Debug)]
+pub(crate) struct WordStream(pub(crate) std::collections::VecDeque<&'static str>);
+
+#[derive(Debug)]
+pub(crate) struct FailingReader(pub(crate) bool);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct UploadHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ProtocolHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FrameListHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FrameScopeHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HeldWriterHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DelayedReplyHandler;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PipelineLoad {
+    pub(crate) active: Arc<AtomicUsize>,
+    pub(crate) peak: Arc<AtomicUsize>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MultiplexHandler;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CancelProbe(pub(crate) Arc<AtomicBool>);
+
+pub(crate) struct CancelGuard(pub(crate) Arc<CancelProbe>);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FlushingReplyHandler;